@@ -1,9 +1,39 @@
-use crate::bible::{loader::BibleLoader, Chapter, VerseReference};
-use crate::config::{BookmarkManager, ReadingState, Settings};
+use crate::bible::analytics::WordCounts;
+use crate::bible::daily::{load_verse_of_the_day, DailyVerseSource};
+use crate::bible::plans::ReadingPlan;
+use crate::bible::versification::{is_valid_reference, verse_count};
+use crate::bible::crossrefs::{CrossRefIndex, CrossReference};
+use crate::bible::modules::{ModuleEntry, ModuleInfo, ModuleStore};
+use crate::bible::search::{group_by_rendering, Concordance, SearchQuery};
+use crate::bible::strongs::{self, Lexicon, LexiconEntry};
+use crate::bible::{loader::BibleLoader, Chapter, Heading, Passage, Verse, VerseReference};
+use crate::config::{
+    BookmarkManager, HighlightColor, HighlightStore, NoteStore, PlanProgress, ReadChapters,
+    ReadingHistory, ReadingState, Settings, WordMarkStore,
+};
+use crate::config::settings::ReadingLayout;
+use crate::config::word_marks::{word_ranges, WordStyle};
 use crate::input::Action;
 use crate::ui::themes::{get_theme, Theme};
+use crate::ui::bookmark_prompt::BookmarkPrompt;
+use crate::ui::bookmarks::BookmarkSort;
+use crate::ui::note_editor::{NoteEditor, NoteTarget};
 use crate::ui::verse_selector::SelectorStep;
 use anyhow::Result;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Gaps between actions longer than this count as idle, not reading time
+const IDLE_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// Most cross-references listed for one verse
+const MAX_CROSS_REFERENCES: usize = 50;
+
+/// Most section headings the go-to selector lists under the books
+const MAX_HEADING_MATCHES: usize = 30;
+
+/// Words listed under each heading of the analytics panel
+const ANALYTICS_WORDS: usize = 10;
 
 /// Main application state
 ///
@@ -25,15 +55,56 @@ pub struct App {
     /// Reading state
     pub state: ReadingState,
 
+    /// Progress through the active reading plan
+    pub plan_progress: PlanProgress,
+
+    /// Built-in and user-defined reading plans
+    pub plans: Vec<ReadingPlan>,
+
+    /// Plan reading opened from the reading plan panel, completed by scrolling through it
+    pub active_reading: Option<ActiveReading>,
+
+    /// Log of reading sessions and chapters visited
+    pub history: ReadingHistory,
+
+    /// Time of the last user action, for measuring reading time
+    last_activity: Instant,
+
+    /// Study notes attached to verses
+    pub notes: NoteStore,
+
+    /// Note being edited, if the note editor is open
+    pub note_editor: Option<NoteEditor>,
+
+    /// Verse whose note should be opened in the external editor; the main
+    /// loop takes this, suspends the terminal and calls `edit_note_externally`
+    pub external_edit: Option<VerseReference>,
+
+    /// One-line message shown in the footer until the next action
+    pub status_message: Option<String>,
+
+    /// Chapters finished, by reaching the last verse or by manual toggle
+    pub read_chapters: ReadChapters,
+
+    /// Reader position at the last auto-mark check, so a chapter is only
+    /// marked when the last verse is reached, not on every action there
+    last_read_position: Option<(String, u32, usize)>,
+
     /// Current theme
     pub theme: Theme,
 
     /// Bible data loader
     pub loader: Option<BibleLoader>,
 
+    /// Translation the loader reads, which word marks are kept against
+    pub translation: String,
+
     /// Currently loaded chapter
     pub current_chapter: Option<Chapter>,
 
+    /// Verse of the day, shown on the welcome screen
+    pub daily_verse: Option<Verse>,
+
     /// Current view mode
     pub view_mode: ViewMode,
 
@@ -46,15 +117,133 @@ pub struct App {
     /// Selected search result index
     pub search_selected: usize,
 
+    /// For Strong's searches, each English rendering and how many of the
+    /// results (which are ordered by group) use it
+    pub search_groups: Vec<(String, usize)>,
+
+    /// Concordance view: the word typed, its concordance once built, the
+    /// selected occurrence and whether lines show keyword-in-context
+    pub concordance_query: String,
+    pub concordance: Option<Concordance>,
+    pub concordance_selected: usize,
+    pub concordance_kwic: bool,
+
     /// Current verse index (the verse being read, highlighted)
     pub current_verse_index: usize,
 
     /// Scroll offset (first visible verse index)
     pub scroll_offset: usize,
 
-    /// Selected bookmark index (when viewing bookmarks)
+    /// Selected bookmark index (when viewing bookmarks), into `visible_bookmarks()`
     pub bookmark_selected: usize,
 
+    /// Bookmarks view ordering
+    pub bookmark_sort: BookmarkSort,
+
+    /// Bookmarks view filter text, and whether it's being typed
+    pub bookmark_filter: String,
+    pub bookmark_filter_active: bool,
+
+    /// Group the Bookmarks view by collection
+    pub bookmark_grouped: bool,
+
+    /// First verse of a range selected with 'M', for bookmarking or highlighting
+    pub selection_anchor: Option<VerseReference>,
+
+    /// Collection and tags prompt, shown when adding or relabelling a bookmark
+    pub bookmark_prompt: Option<BookmarkPrompt>,
+
+    /// Last deleted bookmark and its position, for undo
+    pub bookmark_undo: Option<(usize, crate::config::Bookmark)>,
+
+    /// Verse text shown under each bookmark or highlight, loaded when a list view opens
+    pub verse_previews: HashMap<VerseReference, String>,
+
+    /// Verse highlights
+    pub highlights: HighlightStore,
+
+    /// Underline, bold and color marks on words within verses
+    pub word_marks: WordMarkStore,
+
+    /// Word cursor in the current verse, while marking words
+    pub word_cursor: Option<WordCursor>,
+
+    /// Imported cross-reference index, if any
+    pub crossrefs: Option<CrossRefIndex>,
+
+    /// Cross-references side panel: open, focused for j/k/Enter, selected row
+    pub crossref_panel_open: bool,
+    pub crossref_focus: bool,
+    pub crossref_selected: usize,
+
+    /// Cross-references for `crossref_verse`, with preview text
+    pub crossref_entries: Vec<(CrossReference, String)>,
+    pub crossref_verse: Option<VerseReference>,
+
+    /// Section headings in the translation, in Bible order
+    pub headings: Vec<Heading>,
+
+    /// Outline side panel of the current book's headings: open, focused
+    /// for j/k/Enter, selected row
+    pub outline_open: bool,
+    pub outline_focus: bool,
+    pub outline_selected: usize,
+
+    /// Imported commentaries, dictionaries and topical indexes, if any
+    pub modules: Option<ModuleStore>,
+    pub module_list: Vec<ModuleInfo>,
+
+    /// Companion pane showing a module's entries for the current verse:
+    /// open, focused for j/k scrolling and h/l to switch module
+    pub companion_open: bool,
+    pub companion_focus: bool,
+    pub companion_scroll: u16,
+
+    /// Entries of the selected module for `companion_verse`
+    pub companion_entries: Vec<ModuleEntry>,
+    pub companion_verse: Option<VerseReference>,
+
+    /// Analytics panel of word statistics for the current chapter, or the
+    /// whole book: open, focused for j/k scrolling and h/l to switch scope
+    pub analytics_open: bool,
+    pub analytics_focus: bool,
+    pub analytics_scroll: u16,
+    pub analytics_book: bool,
+
+    /// Counts for the book and chapter (none for the whole book) in
+    /// `analytics_for`
+    pub analytics: Option<WordCounts>,
+    pub analytics_for: Option<(String, Option<u32>)>,
+
+    /// Most frequent and most distinctive words of `analytics`, and the
+    /// number of lines the panel shows, which bounds its scrolling
+    pub analytics_frequent: Vec<(String, usize)>,
+    pub analytics_distinctive: Vec<(String, usize)>,
+    pub analytics_lines: u16,
+
+    /// Counts for the whole translation, loaded when analytics first open
+    pub translation_counts: Option<WordCounts>,
+
+    /// Locations left by following cross-references, most recent last
+    pub back_stack: Vec<VerseReference>,
+
+    /// Imported Strong's lexicon, if any
+    pub lexicon: Option<Lexicon>,
+
+    /// Lexicon popup for the word under the word cursor
+    pub lexicon_popup: Option<LexiconPopup>,
+
+    /// Occurrence counts by Strong's number for the current translation
+    strongs_counts: HashMap<String, usize>,
+
+    /// Highlighter color picker modal and its selected row
+    pub highlight_picker_open: bool,
+    pub highlight_picker_index: usize,
+
+    /// Highlights view: color filter (None shows all) and selected row
+    pub highlight_filter: Option<HighlightColor>,
+    pub highlight_selected: usize,
+
     /// Vim normal mode (true) or insert mode (false)
     pub vim_normal_mode: bool,
 
@@ -72,6 +261,9 @@ pub struct App {
     /// Help modal state
     pub help_open: bool,
 
+    /// Footnotes popup for the current verse
+    pub footnotes_open: bool,
+
     /// Settings modal state
     pub settings_selected_index: usize,
 
@@ -79,10 +271,60 @@ pub struct App {
     pub theme_picker_open: bool,
     pub theme_picker_index: usize,
 
+    /// Statistics view scroll position (per-book coverage list)
+    pub stats_scroll: usize,
+
+    /// Coverage map cursor: index into BOOK_ORDER and chapter number (1-based)
+    pub coverage_book: usize,
+    pub coverage_chapter: u32,
+
+    /// Reading plan panel state
+    pub plan_open: bool,
+    pub plan_picking: bool,
+    pub plan_index: usize,
+
     /// Whether the app should quit
     pub should_quit: bool,
 }
 
+/// Word cursor inside the current verse, for marking words and phrases
+#[derive(Debug, Clone, Default)]
+pub struct WordCursor {
+    /// Index of the word under the cursor
+    pub word: usize,
+    /// Word where a phrase selection started, if extending one
+    pub anchor: Option<usize>,
+}
+
+impl WordCursor {
+    /// First and last selected word indexes
+    pub fn selected_words(&self) -> (usize, usize) {
+        let anchor = self.anchor.unwrap_or(self.word);
+        (anchor.min(self.word), anchor.max(self.word))
+    }
+}
+
+/// Lexicon popup for a word picked with the word cursor
+#[derive(Debug, Clone)]
+pub struct LexiconPopup {
+    /// The word as it appears in the verse
+    pub word: String,
+    /// Each Strong's number on the word, its entry if imported, and how
+    /// many words in the translation carry it
+    pub entries: Vec<(String, Option<LexiconEntry>, usize)>,
+}
+
+/// A plan reading currently open in the Reader
+#[derive(Debug, Clone)]
+pub struct ActiveReading {
+    /// Plan day (1-based)
+    pub day: usize,
+    /// Index of the reading within the day
+    pub index: usize,
+    /// The assigned passage
+    pub passage: Passage,
+}
+
 /// Different view modes available in the application
 ///
 /// The application can be in one of several modes, each displaying different
@@ -100,6 +342,14 @@ pub enum ViewMode {
     Settings,
     /// Help screen with keyboard shortcuts
     Help,
+    /// Reading history and statistics dashboard
+    Stats,
+    /// Whole-Bible map of chapters read
+    Coverage,
+    /// List of highlighted passages, filterable by color
+    Highlights,
+    /// Every occurrence of a word, grouped by book
+    Concordance,
 }
 
 impl App {
@@ -108,22 +358,110 @@ impl App {
         let settings = Settings::load()?;
         let bookmarks = BookmarkManager::load()?;
         let state = ReadingState::load()?;
+        let plan_progress = PlanProgress::load()?;
+        let plans = crate::config::plans::load_plans()?;
+        let mut history = ReadingHistory::load()?;
+        history.begin_session(chrono::Local::now());
+        let read_chapters = ReadChapters::load()?;
+        let notes = NoteStore::load()?;
         let theme = get_theme(&settings.theme);
 
+        // Cross-references are optional; they exist once a dataset is imported
+        let crossrefs = crate::config::crossrefs_path()
+            .ok()
+            .filter(|path| path.exists())
+            .and_then(|path| CrossRefIndex::open(path).ok());
+        let lexicon = crate::config::lexicon_path()
+            .ok()
+            .filter(|path| path.exists())
+            .and_then(|path| Lexicon::open(path).ok());
+        let modules = crate::config::modules_path()
+            .ok()
+            .filter(|path| path.exists())
+            .and_then(|path| ModuleStore::open(path).ok());
+        let module_list = modules
+            .as_ref()
+            .and_then(|store| store.modules().ok())
+            .unwrap_or_default();
+
         Ok(Self {
             settings,
             bookmarks,
             state,
+            plan_progress,
+            plans,
+            active_reading: None,
+            history,
+            last_activity: Instant::now(),
+            notes,
+            note_editor: None,
+            external_edit: None,
+            status_message: None,
+            read_chapters,
+            last_read_position: None,
             theme,
             loader: None,
+            translation: String::new(),
             current_chapter: None,
+            daily_verse: None,
             view_mode: ViewMode::Reader,
             search_query: String::new(),
             search_results: Vec::new(),
             search_selected: 0,
+            search_groups: Vec::new(),
+            concordance_query: String::new(),
+            concordance: None,
+            concordance_selected: 0,
+            concordance_kwic: false,
             current_verse_index: 0,
             scroll_offset: 0,
             bookmark_selected: 0,
+            bookmark_sort: BookmarkSort::Canonical,
+            bookmark_filter: String::new(),
+            bookmark_filter_active: false,
+            bookmark_grouped: false,
+            selection_anchor: None,
+            bookmark_prompt: None,
+            bookmark_undo: None,
+            verse_previews: HashMap::new(),
+            highlights: HighlightStore::load()?,
+            word_marks: WordMarkStore::load()?,
+            word_cursor: None,
+            crossrefs,
+            crossref_panel_open: false,
+            crossref_focus: false,
+            crossref_selected: 0,
+            crossref_entries: Vec::new(),
+            crossref_verse: None,
+            headings: Vec::new(),
+            outline_open: false,
+            outline_focus: false,
+            outline_selected: 0,
+            modules,
+            module_list,
+            companion_open: false,
+            companion_focus: false,
+            companion_scroll: 0,
+            companion_entries: Vec::new(),
+            companion_verse: None,
+            analytics_open: false,
+            analytics_focus: false,
+            analytics_scroll: 0,
+            analytics_book: false,
+            analytics: None,
+            analytics_for: None,
+            analytics_frequent: Vec::new(),
+            analytics_distinctive: Vec::new(),
+            analytics_lines: 0,
+            translation_counts: None,
+            back_stack: Vec::new(),
+            lexicon,
+            lexicon_popup: None,
+            strongs_counts: HashMap::new(),
+            highlight_picker_open: false,
+            highlight_picker_index: 0,
+            highlight_filter: None,
+            highlight_selected: 0,
             vim_normal_mode: true,
             selector_open: false,
             selector_step: SelectorStep::Book,
@@ -133,21 +471,40 @@ impl App {
             selector_selected_chapter: None,
             settings_open: false,
             help_open: false,
+            footnotes_open: false,
             settings_selected_index: 0,
             theme_picker_open: false,
             theme_picker_index: 0,
+            stats_scroll: 0,
+            coverage_book: 0,
+            coverage_chapter: 1,
+            plan_open: false,
+            plan_picking: false,
+            plan_index: 0,
             should_quit: false,
         })
     }
 
-    /// Initialize with a Bible database
-    pub fn with_bible(mut self, db_path: &str) -> Result<Self> {
+    /// Initialize with the database of a translation
+    pub fn with_bible(mut self, translation: &str, db_path: &str) -> Result<Self> {
         let loader = BibleLoader::new(db_path)?;
+        self.translation = translation.to_string();
+        self.headings = loader.load_headings()?;
         self.loader = Some(loader);
+        self.load_daily_verse()?;
         self.load_current_chapter()?;
         Ok(self)
     }
 
+    /// Load today's verse of the day from the configured source
+    pub fn load_daily_verse(&mut self) -> Result<()> {
+        if let Some(loader) = &self.loader {
+            let today = chrono::Local::now().date_naive();
+            self.daily_verse = load_verse_of_the_day(loader, self.settings.daily_verse, today)?;
+        }
+        Ok(())
+    }
+
     /// Load the current chapter based on reading state
     pub fn load_current_chapter(&mut self) -> Result<()> {
         if let (Some(loader), Some(book), Some(chapter)) = (
@@ -163,6 +520,9 @@ impl App {
 
     /// Handle an action
     pub fn handle_action(&mut self, action: Action) -> Result<()> {
+        self.track_reading_time();
+        self.status_message = None;
+
         // If selector is open, intercept navigation actions
         if self.selector_open {
             match action {
@@ -191,7 +551,8 @@ impl App {
                 Action::Char(c) if c.is_ascii_digit() => {
                     // Quick number jump for chapters/verses
                     if let Some(digit) = c.to_digit(10) {
-                        self.selector_index = (digit as usize).saturating_sub(1);
+                        let max = self.selector_item_count().saturating_sub(1);
+                        self.selector_index = (digit as usize).saturating_sub(1).min(max);
                     }
                     return Ok(());
                 }
@@ -256,8 +617,9 @@ impl App {
                     return Ok(());
                 }
                 Action::ScrollDown => {
-                    // Max index is 2: theme (0), verse numbers (1), verse spacing (2)
-                    if self.settings_selected_index < 2 {
+                    // Max index is 5: theme (0), verse numbers (1), verse spacing (2), daily verse (3),
+                    // Strong's numbers (4), layout (5)
+                    if self.settings_selected_index < 5 {
                         self.settings_selected_index += 1;
                     }
                     return Ok(());
@@ -281,6 +643,28 @@ impl App {
                             self.settings.verse_spacing = !self.settings.verse_spacing;
                             self.settings.save()?;
                         }
+                        3 => {
+                            // Toggle verse of the day source
+                            self.settings.daily_verse = match self.settings.daily_verse {
+                                DailyVerseSource::Curated => DailyVerseSource::Random,
+                                DailyVerseSource::Random => DailyVerseSource::Curated,
+                            };
+                            self.settings.save()?;
+                            self.load_daily_verse()?;
+                        }
+                        4 => {
+                            // Toggle Strong's numbers
+                            self.settings.show_strongs = !self.settings.show_strongs;
+                            self.settings.save()?;
+                        }
+                        5 => {
+                            // Switch between verse and paragraph layout
+                            self.settings.layout = match self.settings.layout {
+                                ReadingLayout::Verses => ReadingLayout::Paragraphs,
+                                ReadingLayout::Paragraphs => ReadingLayout::Verses,
+                            };
+                            self.settings.save()?;
+                        }
                         _ => {}
                     }
                     return Ok(());
@@ -298,6 +682,183 @@ impl App {
             }
         }
 
+        // If note editor is open, all input edits the note
+        if let Some(editor) = &mut self.note_editor {
+            match action {
+                Action::Escape => self.note_editor = None,
+                Action::SaveNote => {
+                    if let Some(editor) = self.note_editor.take() {
+                        let text = editor.text();
+                        match editor.target {
+                            NoteTarget::Verse => {
+                                self.notes.set(editor.reference, text);
+                                self.notes.save()?;
+                            }
+                            NoteTarget::Bookmark => {
+                                self.bookmarks.set_note(&editor.reference, text);
+                                self.bookmarks.save()?;
+                            }
+                        }
+                    }
+                }
+                Action::Quit => self.should_quit = true,
+                Action::Char(c) => editor.insert_char(c),
+                Action::Enter => editor.newline(),
+                Action::Backspace => editor.backspace(),
+                Action::Delete => editor.delete(),
+                Action::ScrollUp => editor.move_up(),
+                Action::ScrollDown => editor.move_down(),
+                Action::PreviousVerse => editor.move_left(),
+                Action::NextVerse => editor.move_right(),
+                Action::GoToTop => editor.move_home(),
+                Action::GoToBottom => editor.move_end(),
+                _ => {}
+            }
+            return Ok(());
+        }
+
+        // If the lexicon popup is open, only allow closing and quit
+        if self.lexicon_popup.is_some() {
+            match action {
+                Action::Escape | Action::Enter => self.lexicon_popup = None,
+                Action::Quit => self.should_quit = true,
+                _ => {}
+            }
+            return Ok(());
+        }
+
+        // If the word cursor is active, keys move it and mark words
+        if self.word_cursor.is_some() {
+            return self.handle_word_action(action);
+        }
+
+        // If the highlighter color picker is open, handle its keys
+        if self.highlight_picker_open {
+            let colors = HighlightColor::ALL.len();
+            match action {
+                Action::ScrollDown | Action::Char('j') => {
+                    // Rows are the colors, then "Clear"
+                    self.highlight_picker_index = (self.highlight_picker_index + 1).min(colors);
+                }
+                Action::ScrollUp | Action::Char('k') => {
+                    self.highlight_picker_index = self.highlight_picker_index.saturating_sub(1);
+                }
+                Action::Enter => {
+                    self.highlight_picker_open = false;
+                    let color = HighlightColor::ALL.get(self.highlight_picker_index).copied();
+                    self.apply_highlight(color)?;
+                }
+                Action::Char(c) if c.is_ascii_digit() => {
+                    self.highlight_picker_open = false;
+                    let color = c.to_digit(10).and_then(HighlightColor::from_digit);
+                    self.apply_highlight(color)?;
+                }
+                Action::Char('x') => {
+                    self.highlight_picker_open = false;
+                    self.apply_highlight(None)?;
+                }
+                Action::Escape => self.highlight_picker_open = false,
+                Action::Quit => self.should_quit = true,
+                _ => {}
+            }
+            return Ok(());
+        }
+
+        // If the bookmark labels prompt is open, keys edit its input
+        if let Some(prompt) = &mut self.bookmark_prompt {
+            match action {
+                Action::Char(c) => prompt.input.push(c),
+                Action::Backspace => {
+                    prompt.input.pop();
+                }
+                Action::Tab => {
+                    let collections = self.bookmarks.collections();
+                    let tags = self.bookmarks.tags();
+                    prompt.complete(&collections, &tags);
+                }
+                Action::Enter => {
+                    if let Some(prompt) = self.bookmark_prompt.take() {
+                        if !prompt.editing {
+                            let bookmark = match &prompt.end {
+                                Some(end) => crate::config::Bookmark::range(
+                                    prompt.reference.clone(),
+                                    end.clone(),
+                                ),
+                                None => crate::config::Bookmark::new(prompt.reference.clone()),
+                            };
+                            self.bookmarks.add(bookmark);
+                        }
+                        let (collection, tags) = prompt.labels();
+                        self.bookmarks.set_labels(&prompt.reference, collection, tags);
+                        self.bookmarks.save()?;
+                    }
+                }
+                Action::Escape => self.bookmark_prompt = None,
+                Action::Quit => self.should_quit = true,
+                _ => {}
+            }
+            return Ok(());
+        }
+
+        // If the bookmarks filter is being typed, keys edit the filter
+        if self.bookmark_filter_active {
+            match action {
+                Action::Char(c) => {
+                    self.bookmark_filter.push(c);
+                    self.bookmark_selected = 0;
+                }
+                Action::Backspace => {
+                    self.bookmark_filter.pop();
+                    self.bookmark_selected = 0;
+                }
+                Action::Enter | Action::Escape => self.bookmark_filter_active = false,
+                Action::ScrollDown => self.move_bookmark_selection(1),
+                Action::ScrollUp => self.move_bookmark_selection(-1),
+                Action::Quit => self.should_quit = true,
+                _ => {}
+            }
+            return Ok(());
+        }
+
+        // If reading plan panel is open, handle navigation and selection
+        if self.plan_open {
+            match action {
+                Action::Escape => {
+                    self.plan_open = false;
+                    return Ok(());
+                }
+                Action::ScrollUp => {
+                    self.plan_index = self.plan_index.saturating_sub(1);
+                    return Ok(());
+                }
+                Action::ScrollDown => {
+                    if self.plan_index + 1 < self.plan_item_count() {
+                        self.plan_index += 1;
+                    }
+                    return Ok(());
+                }
+                Action::Enter => {
+                    return self.handle_plan_select();
+                }
+                Action::Quit => {
+                    self.should_quit = true;
+                    return Ok(());
+                }
+                // Block all other actions
+                _ => return Ok(()),
+            }
+        }
+
+        // If the footnotes popup is open, only allow closing and quit
+        if self.footnotes_open {
+            match action {
+                Action::Escape | Action::Enter | Action::OpenFootnotes => self.footnotes_open = false,
+                Action::Quit => self.should_quit = true,
+                _ => {}
+            }
+            return Ok(());
+        }
+
         // If help modal is open, only allow closing and quit
         if self.help_open {
             match action {
@@ -317,152 +878,1427 @@ impl App {
         match action {
             Action::Quit => self.should_quit = true,
 
-            Action::OpenVerseSelector => {
-                self.selector_open = true;
-                self.selector_step = SelectorStep::Book;
-                self.selector_search.clear();
-                self.selector_index = 0;
-                self.selector_selected_book = None;
-                self.selector_selected_chapter = None;
+            // Statistics view scrolls its own list instead of the Reader
+            Action::ScrollDown if self.view_mode == ViewMode::Stats => {
+                self.stats_scroll = (self.stats_scroll + 1).min(crate::bible::BOOK_ORDER.len().saturating_sub(1));
             }
 
-            Action::ScrollDown => {
-                if let Some(chapter) = &self.current_chapter {
-                    if self.current_verse_index < chapter.verses.len().saturating_sub(1) {
-                        self.current_verse_index += 1;
-                        self.adjust_scroll_for_current_verse();
-                    } else {
-                        // At end of chapter, try to go to next chapter
-                        if let Some(current_chapter) = self.state.current_chapter {
-                            let next_chapter_num = current_chapter + 1;
-                            if let (Some(loader), Some(book)) = (&self.loader, &self.state.current_book) {
-                                if let Ok(next_chapter) = loader.load_chapter(book, next_chapter_num) {
-                                    if !next_chapter.verses.is_empty() {
-                                        self.state.current_chapter = Some(next_chapter_num);
-                                        self.current_chapter = Some(next_chapter);
-                                        self.current_verse_index = 0;
-                                        self.scroll_offset = 0;
-                                    } else {
-                                        self.try_next_book()?;
-                                    }
-                                } else {
-                                    self.try_next_book()?;
-                                }
-                            }
-                        }
-                    }
-                }
+            Action::ScrollUp if self.view_mode == ViewMode::Stats => {
+                self.stats_scroll = self.stats_scroll.saturating_sub(1);
             }
 
-            Action::ScrollUp => {
-                if self.current_verse_index > 0 {
-                    self.current_verse_index -= 1;
-                    self.adjust_scroll_for_current_verse();
-                } else {
-                    // At beginning of chapter, try to go to previous chapter
-                    if let Some(current_chapter) = self.state.current_chapter {
-                        if current_chapter > 1 {
-                            let prev_chapter_num = current_chapter - 1;
-                            if let (Some(loader), Some(book)) = (&self.loader, &self.state.current_book) {
-                                if let Ok(prev_chapter) = loader.load_chapter(book, prev_chapter_num) {
-                                    self.state.current_chapter = Some(prev_chapter_num);
-                                    self.current_verse_index = prev_chapter.verses.len().saturating_sub(1);
-                                    self.current_chapter = Some(prev_chapter);
-                                    // Set scroll to show last verses
-                                    self.scroll_offset = self.current_verse_index.saturating_sub(20);
-                                }
-                            }
-                        } else {
-                            self.try_previous_book()?;
-                        }
-                    }
-                }
+            // Search view: Enter runs the query, then opens the selected result
+            Action::ScrollDown if self.view_mode == ViewMode::Search => {
+                let last = self.search_results.len().saturating_sub(1);
+                self.search_selected = (self.search_selected + 1).min(last);
             }
 
-            Action::PageDown => {
-                if let Some(chapter) = &self.current_chapter {
-                    self.current_verse_index = (self.current_verse_index + 10)
-                        .min(chapter.verses.len().saturating_sub(1));
-                    self.scroll_offset = self.scroll_offset.saturating_add(10);
+            Action::ScrollUp if self.view_mode == ViewMode::Search => {
+                self.search_selected = self.search_selected.saturating_sub(1);
+            }
+
+            Action::Enter if self.view_mode == ViewMode::Search => {
+                match self.search_results.get(self.search_selected).cloned() {
+                    Some(reference) => {
+                        self.go_to_reference(&reference)?;
+                        self.view_mode = ViewMode::Reader;
+                        self.vim_normal_mode = true;
+                    }
+                    None => self.run_search()?,
                 }
             }
 
-            Action::PageUp => {
-                self.current_verse_index = self.current_verse_index.saturating_sub(10);
-                self.scroll_offset = self.scroll_offset.saturating_sub(10);
+            // Concordance view: Enter builds it, then opens the selected
+            // occurrence; Tab switches between grouped and KWIC lines
+            Action::ScrollDown if self.view_mode == ViewMode::Concordance => {
+                let last = self.concordance.as_ref().map_or(0, Concordance::total).saturating_sub(1);
+                self.concordance_selected = (self.concordance_selected + 1).min(last);
             }
 
-            Action::GoToTop => {
-                self.current_verse_index = 0;
-                self.scroll_offset = 0;
+            Action::ScrollUp if self.view_mode == ViewMode::Concordance => {
+                self.concordance_selected = self.concordance_selected.saturating_sub(1);
             }
 
-            Action::GoToBottom => {
-                if let Some(chapter) = &self.current_chapter {
-                    self.current_verse_index = chapter.verses.len().saturating_sub(1);
-                    self.scroll_offset = self.current_verse_index.saturating_sub(20);
+            Action::Enter if self.view_mode == ViewMode::Concordance => {
+                let selected = self
+                    .concordance
+                    .as_ref()
+                    .and_then(|concordance| concordance.occurrences().nth(self.concordance_selected))
+                    .map(|occurrence| occurrence.reference.clone());
+                match selected {
+                    Some(reference) => {
+                        self.go_to_reference(&reference)?;
+                        self.view_mode = ViewMode::Reader;
+                        self.vim_normal_mode = true;
+                    }
+                    None => self.build_concordance()?,
                 }
             }
 
+            Action::Tab if self.view_mode == ViewMode::Concordance => {
+                self.concordance_kwic = !self.concordance_kwic;
+            }
 
-            Action::OpenSearch => {
-                self.view_mode = ViewMode::Search;
-                self.vim_normal_mode = false;
+            // Cross-reference panel: navigate, follow and go back
+            Action::ScrollDown if self.crossref_focus && self.view_mode == ViewMode::Reader => {
+                let last = self.crossref_entries.len().saturating_sub(1);
+                self.crossref_selected = (self.crossref_selected + 1).min(last);
             }
 
-            Action::Escape => {
-                // Close modals if open
-                if self.settings_open {
-                    self.settings_open = false;
-                } else if self.help_open {
-                    self.help_open = false;
-                } else {
-                    self.view_mode = ViewMode::Reader;
-                    self.vim_normal_mode = true;
-                }
+            Action::ScrollUp if self.crossref_focus && self.view_mode == ViewMode::Reader => {
+                self.crossref_selected = self.crossref_selected.saturating_sub(1);
             }
 
-            Action::OpenBookmarks => {
-                self.view_mode = ViewMode::Bookmarks;
+            Action::Enter if self.crossref_focus && self.view_mode == ViewMode::Reader => {
+                self.follow_cross_reference()?;
             }
 
-            Action::OpenSettings => {
-                self.settings_open = true;
+            Action::Escape if self.crossref_focus && self.view_mode == ViewMode::Reader => {
+                self.crossref_focus = false;
             }
 
-            Action::OpenHelp => {
-                self.help_open = true;
+            // Outline panel: navigate and jump to a section
+            Action::ScrollDown if self.outline_focus && self.view_mode == ViewMode::Reader => {
+                let last = self.outline().len().saturating_sub(1);
+                self.outline_selected = (self.outline_selected + 1).min(last);
             }
 
-            Action::ToggleBookmark => {
-                if let Some(chapter) = &self.current_chapter {
-                    if let Some(verse) = chapter.verses.get(self.current_verse_index) {
-                        if self.bookmarks.is_bookmarked(&verse.reference) {
-                            self.bookmarks.remove(&verse.reference);
-                        } else {
-                            self.bookmarks
-                                .add(crate::config::Bookmark::new(verse.reference.clone()));
-                        }
-                        self.bookmarks.save()?;
-                    }
+            Action::ScrollUp if self.outline_focus && self.view_mode == ViewMode::Reader => {
+                self.outline_selected = self.outline_selected.saturating_sub(1);
+            }
+
+            Action::Enter if self.outline_focus && self.view_mode == ViewMode::Reader => {
+                let target = self
+                    .outline()
+                    .get(self.outline_selected)
+                    .map(|heading| heading.reference.clone());
+                if let Some(target) = target {
+                    self.go_to_reference(&target)?;
                 }
             }
 
+            Action::Escape if self.outline_focus && self.view_mode == ViewMode::Reader => {
+                self.outline_focus = false;
+            }
 
-            Action::Char(c) if self.view_mode == ViewMode::Search => {
-                self.search_query.push(c);
+            // Companion pane: scroll and switch module
+            Action::ScrollDown if self.companion_focus && self.view_mode == ViewMode::Reader => {
+                self.companion_scroll = self.companion_scroll.saturating_add(1);
             }
 
-            Action::Backspace if self.view_mode == ViewMode::Search => {
-                self.search_query.pop();
+            Action::ScrollUp if self.companion_focus && self.view_mode == ViewMode::Reader => {
+                self.companion_scroll = self.companion_scroll.saturating_sub(1);
             }
 
-            _ => {}
-        }
+            Action::NextVerse if self.companion_focus && self.view_mode == ViewMode::Reader => {
+                self.switch_reference_module(1)?;
+            }
 
-        // Persist current reading position to state
-        self.state.current_verse_index = self.current_verse_index;
+            Action::PreviousVerse if self.companion_focus && self.view_mode == ViewMode::Reader => {
+                self.switch_reference_module(-1)?;
+            }
 
+            Action::Escape if self.companion_focus && self.view_mode == ViewMode::Reader => {
+                self.companion_focus = false;
+            }
+
+            // Analytics panel: scroll and switch between chapter and book
+            Action::ScrollDown if self.analytics_focus && self.view_mode == ViewMode::Reader => {
+                self.analytics_scroll =
+                    (self.analytics_scroll + 1).min(self.analytics_lines.saturating_sub(1));
+            }
+
+            Action::ScrollUp if self.analytics_focus && self.view_mode == ViewMode::Reader => {
+                self.analytics_scroll = self.analytics_scroll.saturating_sub(1);
+            }
+
+            Action::NextVerse | Action::PreviousVerse
+                if self.analytics_focus && self.view_mode == ViewMode::Reader =>
+            {
+                self.analytics_book = !self.analytics_book;
+            }
+
+            Action::Escape if self.analytics_focus && self.view_mode == ViewMode::Reader => {
+                self.analytics_focus = false;
+            }
+
+            Action::Tab
+                if (self.crossref_panel_open
+                    || self.outline_open
+                    || self.companion_open
+                    || self.analytics_open)
+                    && self.view_mode == ViewMode::Reader =>
+            {
+                self.cycle_panel_focus();
+            }
+
+            Action::Backspace if self.view_mode == ViewMode::Reader => {
+                if let Some(reference) = self.back_stack.pop() {
+                    self.go_to_reference(&reference)?;
+                }
+            }
+
+            // Highlights view: navigate, jump, delete and filter by color
+            Action::ScrollDown if self.view_mode == ViewMode::Highlights => {
+                self.move_highlight_selection(1);
+            }
+
+            Action::ScrollUp if self.view_mode == ViewMode::Highlights => {
+                self.move_highlight_selection(-1);
+            }
+
+            Action::Enter if self.view_mode == ViewMode::Highlights => {
+                if let Some(start) = self
+                    .visible_highlights()
+                    .get(self.highlight_selected)
+                    .map(|h| h.start.clone())
+                {
+                    self.go_to_reference(&start)?;
+                    self.view_mode = ViewMode::Reader;
+                }
+            }
+
+            Action::Char('d') | Action::Delete if self.view_mode == ViewMode::Highlights => {
+                if let Some(start) = self
+                    .visible_highlights()
+                    .get(self.highlight_selected)
+                    .map(|h| h.start.clone())
+                {
+                    self.highlights.remove(&start);
+                    self.highlights.save()?;
+                    self.move_highlight_selection(0);
+                }
+            }
+
+            Action::Tab if self.view_mode == ViewMode::Highlights => {
+                // All, then each color in turn
+                self.highlight_filter = match self.highlight_filter {
+                    None => Some(HighlightColor::ALL[0]),
+                    Some(color) => HighlightColor::ALL.get(color.index() + 1).copied(),
+                };
+                self.highlight_selected = 0;
+            }
+
+            Action::Char(c) if self.view_mode == ViewMode::Highlights && c.is_ascii_digit() => {
+                self.highlight_filter = c.to_digit(10).and_then(HighlightColor::from_digit);
+                self.highlight_selected = 0;
+            }
+
+            // Bookmarks view: navigate, jump, delete/undo, edit note, sort and filter
+            Action::ScrollDown if self.view_mode == ViewMode::Bookmarks => {
+                self.move_bookmark_selection(1);
+            }
+
+            Action::ScrollUp if self.view_mode == ViewMode::Bookmarks => {
+                self.move_bookmark_selection(-1);
+            }
+
+            Action::Enter if self.view_mode == ViewMode::Bookmarks => {
+                if let Some(reference) = self.selected_bookmark() {
+                    self.go_to_reference(&reference)?;
+                    self.view_mode = ViewMode::Reader;
+                }
+            }
+
+            Action::Char('d') | Action::Delete if self.view_mode == ViewMode::Bookmarks => {
+                if let Some(reference) = self.selected_bookmark() {
+                    self.bookmark_undo = self.bookmarks.take(&reference);
+                    self.bookmarks.save()?;
+                    self.move_bookmark_selection(0);
+                }
+            }
+
+            Action::Char('u') if self.view_mode == ViewMode::Bookmarks => {
+                if let Some((index, bookmark)) = self.bookmark_undo.take() {
+                    self.bookmarks.restore(index, bookmark);
+                    self.bookmarks.save()?;
+                }
+            }
+
+            Action::EditNote if self.view_mode == ViewMode::Bookmarks => {
+                if let Some(reference) = self.selected_bookmark() {
+                    let text = self
+                        .bookmarks
+                        .get(&reference)
+                        .and_then(|bookmark| bookmark.note.clone())
+                        .unwrap_or_default();
+                    self.note_editor = Some(NoteEditor::new(reference, NoteTarget::Bookmark, &text));
+                }
+            }
+
+            Action::Char('t') if self.view_mode == ViewMode::Bookmarks => {
+                if let Some(bookmark) = self
+                    .selected_bookmark()
+                    .and_then(|reference| self.bookmarks.get(&reference))
+                {
+                    let mut prompt = BookmarkPrompt::new(
+                        bookmark.reference.clone(),
+                        bookmark.collection.as_deref(),
+                        &bookmark.tags,
+                        true,
+                    );
+                    prompt.end = bookmark.end.clone();
+                    self.bookmark_prompt = Some(prompt);
+                }
+            }
+
+            Action::Char('c') if self.view_mode == ViewMode::Bookmarks => {
+                self.bookmark_grouped = !self.bookmark_grouped;
+                self.bookmark_selected = 0;
+            }
+
+            Action::Tab if self.view_mode == ViewMode::Bookmarks => {
+                self.bookmark_sort = self.bookmark_sort.next();
+                self.bookmark_selected = 0;
+            }
+
+            Action::OpenSearch if self.view_mode == ViewMode::Bookmarks => {
+                self.bookmark_filter_active = true;
+            }
+
+            Action::Escape if self.view_mode == ViewMode::Bookmarks && !self.bookmark_filter.is_empty() => {
+                self.bookmark_filter.clear();
+                self.bookmark_selected = 0;
+            }
+
+            // Coverage map moves its own cursor instead of the Reader
+            Action::ScrollDown if self.view_mode == ViewMode::Coverage => {
+                self.move_coverage_cursor(1, 0);
+            }
+
+            Action::ScrollUp if self.view_mode == ViewMode::Coverage => {
+                self.move_coverage_cursor(-1, 0);
+            }
+
+            Action::NextVerse if self.view_mode == ViewMode::Coverage => {
+                self.move_coverage_cursor(0, 1);
+            }
+
+            Action::PreviousVerse if self.view_mode == ViewMode::Coverage => {
+                self.move_coverage_cursor(0, -1);
+            }
+
+            Action::Enter if self.view_mode == ViewMode::Coverage => {
+                let (book, _, _, _) = crate::bible::BOOK_ORDER[self.coverage_book];
+                self.state.current_book = Some(book.to_string());
+                self.state.current_chapter = Some(self.coverage_chapter);
+                self.state.current_verse_index = 0;
+                self.load_current_chapter()?;
+                self.scroll_offset = 0;
+                self.view_mode = ViewMode::Reader;
+            }
+
+            Action::ToggleChapterRead if self.view_mode == ViewMode::Coverage => {
+                let (book, _, _, _) = crate::bible::BOOK_ORDER[self.coverage_book];
+                self.read_chapters.toggle(book, self.coverage_chapter);
+                self.read_chapters.save()?;
+            }
+
+            Action::OpenVerseSelector => {
+                self.selector_open = true;
+                self.selector_step = SelectorStep::Book;
+                self.selector_search.clear();
+                self.selector_index = 0;
+                self.selector_selected_book = None;
+                self.selector_selected_chapter = None;
+            }
+
+            Action::ScrollDown => {
+                if let Some(chapter) = &self.current_chapter {
+                    if self.current_verse_index < chapter.verses.len().saturating_sub(1) {
+                        self.current_verse_index += 1;
+                        self.adjust_scroll_for_current_verse();
+                    } else {
+                        // At end of chapter, try to go to next chapter
+                        if let Some(current_chapter) = self.state.current_chapter {
+                            let next_chapter_num = current_chapter + 1;
+                            if let (Some(loader), Some(book)) = (&self.loader, &self.state.current_book) {
+                                if let Ok(next_chapter) = loader.load_chapter(book, next_chapter_num) {
+                                    if !next_chapter.verses.is_empty() {
+                                        self.state.current_chapter = Some(next_chapter_num);
+                                        self.current_chapter = Some(next_chapter);
+                                        self.current_verse_index = 0;
+                                        self.scroll_offset = 0;
+                                    } else {
+                                        self.try_next_book()?;
+                                    }
+                                } else {
+                                    self.try_next_book()?;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            Action::ScrollUp => {
+                if self.current_verse_index > 0 {
+                    self.current_verse_index -= 1;
+                    self.adjust_scroll_for_current_verse();
+                } else {
+                    // At beginning of chapter, try to go to previous chapter
+                    if let Some(current_chapter) = self.state.current_chapter {
+                        if current_chapter > 1 {
+                            let prev_chapter_num = current_chapter - 1;
+                            if let (Some(loader), Some(book)) = (&self.loader, &self.state.current_book) {
+                                if let Ok(prev_chapter) = loader.load_chapter(book, prev_chapter_num) {
+                                    self.state.current_chapter = Some(prev_chapter_num);
+                                    self.current_verse_index = prev_chapter.verses.len().saturating_sub(1);
+                                    self.current_chapter = Some(prev_chapter);
+                                    // Set scroll to show last verses
+                                    self.scroll_offset = self.current_verse_index.saturating_sub(20);
+                                }
+                            }
+                        } else {
+                            self.try_previous_book()?;
+                        }
+                    }
+                }
+            }
+
+            Action::PageDown => {
+                if let Some(chapter) = &self.current_chapter {
+                    self.current_verse_index = (self.current_verse_index + 10)
+                        .min(chapter.verses.len().saturating_sub(1));
+                    self.scroll_offset = self.scroll_offset.saturating_add(10);
+                }
+            }
+
+            Action::PageUp => {
+                self.current_verse_index = self.current_verse_index.saturating_sub(10);
+                self.scroll_offset = self.scroll_offset.saturating_sub(10);
+            }
+
+            Action::GoToTop => {
+                self.current_verse_index = 0;
+                self.scroll_offset = 0;
+            }
+
+            Action::GoToBottom => {
+                if let Some(chapter) = &self.current_chapter {
+                    self.current_verse_index = chapter.verses.len().saturating_sub(1);
+                    self.scroll_offset = self.current_verse_index.saturating_sub(20);
+                }
+            }
+
+
+            Action::OpenSearch => {
+                self.view_mode = ViewMode::Search;
+                self.vim_normal_mode = false;
+            }
+
+            Action::OpenConcordance => {
+                self.view_mode = ViewMode::Concordance;
+                self.vim_normal_mode = false;
+            }
+
+            Action::Escape => {
+                // Close modals if open
+                if self.settings_open {
+                    self.settings_open = false;
+                } else if self.help_open {
+                    self.help_open = false;
+                } else if self.selection_anchor.is_some() {
+                    self.selection_anchor = None;
+                } else {
+                    self.view_mode = ViewMode::Reader;
+                    self.vim_normal_mode = true;
+                }
+            }
+
+            Action::EditNote => {
+                if let Some(verse) = self
+                    .current_chapter
+                    .as_ref()
+                    .and_then(|chapter| chapter.verses.get(self.current_verse_index))
+                {
+                    let text = self
+                        .notes
+                        .get(&verse.reference)
+                        .map(|note| note.text.as_str())
+                        .unwrap_or("");
+                    self.note_editor =
+                        Some(NoteEditor::new(verse.reference.clone(), NoteTarget::Verse, text));
+                }
+            }
+
+            Action::EditNoteExternal => {
+                if let Some(verse) = self
+                    .current_chapter
+                    .as_ref()
+                    .and_then(|chapter| chapter.verses.get(self.current_verse_index))
+                {
+                    self.external_edit = Some(verse.reference.clone());
+                }
+            }
+
+            Action::OpenBookmarks => {
+                self.view_mode = ViewMode::Bookmarks;
+                self.bookmark_selected = 0;
+                self.bookmark_undo = None;
+                self.load_verse_previews(
+                    self.bookmarks.all().iter().map(|b| b.reference.clone()).collect(),
+                )?;
+            }
+
+            Action::OpenHighlights => {
+                self.view_mode = ViewMode::Highlights;
+                self.highlight_selected = 0;
+                self.load_verse_previews(
+                    self.highlights.all().iter().map(|h| h.start.clone()).collect(),
+                )?;
+            }
+
+            Action::ToggleCrossRefs if self.view_mode == ViewMode::Reader => {
+                self.crossref_panel_open = !self.crossref_panel_open;
+                self.crossref_focus = self.crossref_panel_open;
+                self.outline_focus = false;
+                self.companion_focus = false;
+                self.analytics_focus = false;
+                if self.crossref_panel_open && self.crossrefs.is_none() {
+                    self.status_message = Some(
+                        "No cross-references imported; run 'biblios import-crossrefs <file>'"
+                            .to_string(),
+                    );
+                }
+            }
+
+            Action::ToggleOutline if self.view_mode == ViewMode::Reader => {
+                self.outline_open = !self.outline_open;
+                self.outline_focus = self.outline_open;
+                self.crossref_focus = false;
+                self.companion_focus = false;
+                self.analytics_focus = false;
+                if self.outline_open {
+                    self.outline_selected = self.current_section().unwrap_or(0);
+                    if self.outline().is_empty() {
+                        self.status_message =
+                            Some("This translation has no section headings for this book".to_string());
+                    }
+                }
+            }
+
+            Action::ToggleCompanion if self.view_mode == ViewMode::Reader => {
+                self.companion_open = !self.companion_open;
+                self.companion_focus = false;
+                self.companion_verse = None;
+                if self.companion_open && self.module_list.is_empty() {
+                    self.status_message = Some(
+                        "No reference modules imported; run 'biblios import-module <file>'"
+                            .to_string(),
+                    );
+                }
+            }
+
+            Action::ToggleAnalytics if self.view_mode == ViewMode::Reader => {
+                self.analytics_open = !self.analytics_open;
+                self.analytics_focus = self.analytics_open;
+                self.crossref_focus = false;
+                self.outline_focus = false;
+                self.companion_focus = false;
+            }
+
+            Action::OpenFootnotes if self.view_mode == ViewMode::Reader => {
+                let has_notes = self
+                    .current_verse()
+                    .is_some_and(|verse| !verse.content.notes.is_empty());
+                if has_notes {
+                    self.footnotes_open = true;
+                } else {
+                    self.status_message = Some("No footnotes on this verse".to_string());
+                }
+            }
+
+            Action::EnterWordMode => {
+                let has_words = self
+                    .current_verse()
+                    .is_some_and(|verse| !word_ranges(&verse.text).is_empty());
+                if has_words {
+                    self.word_cursor = Some(WordCursor::default());
+                }
+            }
+
+            Action::OpenHighlighter if self.current_chapter.is_some() => {
+                self.highlight_picker_open = true;
+                self.highlight_picker_index = 0;
+            }
+
+            Action::OpenSettings => {
+                self.settings_open = true;
+            }
+
+            Action::ToggleChapterRead => {
+                if let Some(chapter) = &self.current_chapter {
+                    self.read_chapters.toggle(&chapter.book, chapter.chapter_number);
+                    self.read_chapters.save()?;
+                }
+            }
+
+            Action::OpenCoverage => {
+                self.view_mode = ViewMode::Coverage;
+                if let (Some(book), Some(chapter)) = (&self.state.current_book, self.state.current_chapter) {
+                    self.coverage_book = crate::bible::BOOK_ORDER
+                        .iter()
+                        .position(|(name, _, _, _)| name == book)
+                        .unwrap_or(0);
+                    self.coverage_chapter = chapter;
+                }
+            }
+
+            Action::OpenStats => {
+                self.view_mode = ViewMode::Stats;
+                self.stats_scroll = 0;
+            }
+
+            Action::OpenReadingPlan => {
+                self.plan_open = true;
+                self.plan_picking = self.active_plan().is_none();
+                self.plan_index = 0;
+            }
+
+            Action::OpenHelp => {
+                self.help_open = true;
+            }
+
+            Action::ToggleBookmark => {
+                if let Some(chapter) = &self.current_chapter {
+                    if let Some(verse) = chapter.verses.get(self.current_verse_index) {
+                        if let Some(start) = self
+                            .bookmarks
+                            .covering(&verse.reference)
+                            .map(|bookmark| bookmark.reference.clone())
+                        {
+                            // Inside a range this removes the whole range
+                            self.bookmarks.remove(&start);
+                            self.bookmarks.save()?;
+                        } else {
+                            // Ask for a collection and tags; the bookmark is added on Enter
+                            self.bookmark_prompt =
+                                Some(BookmarkPrompt::new(verse.reference.clone(), None, &[], false));
+                        }
+                    }
+                }
+            }
+
+
+            Action::MarkBookmarkRange => {
+                let current = self
+                    .current_chapter
+                    .as_ref()
+                    .and_then(|chapter| chapter.verses.get(self.current_verse_index))
+                    .map(|verse| verse.reference.clone());
+
+                if let Some(current) = current {
+                    match self.selection_anchor.take() {
+                        // Second press in the same book: bookmark the range
+                        Some(anchor) if anchor.book == current.book && anchor != current => {
+                            let bookmark = crate::config::Bookmark::range(anchor, current);
+                            let mut prompt =
+                                BookmarkPrompt::new(bookmark.reference, None, &[], false);
+                            prompt.end = bookmark.end;
+                            self.bookmark_prompt = Some(prompt);
+                        }
+                        // Pressing again on the anchor cancels
+                        Some(anchor) if anchor == current => {
+                            self.status_message = Some("Range bookmark cancelled".to_string());
+                        }
+                        _ => {
+                            self.status_message = Some(format!(
+                                "Range starts at {}; move to the last verse and press M to bookmark or H to highlight",
+                                current
+                            ));
+                            self.selection_anchor = Some(current);
+                        }
+                    }
+                }
+            }
+
+            Action::Char(c) if self.view_mode == ViewMode::Search => {
+                self.search_query.push(c);
+                self.clear_search_results();
+            }
+
+            Action::Backspace if self.view_mode == ViewMode::Search => {
+                self.search_query.pop();
+                self.clear_search_results();
+            }
+
+            Action::Char(c) if self.view_mode == ViewMode::Concordance => {
+                self.concordance_query.push(c);
+                self.concordance = None;
+                self.concordance_selected = 0;
+            }
+
+            Action::Backspace if self.view_mode == ViewMode::Concordance => {
+                self.concordance_query.pop();
+                self.concordance = None;
+                self.concordance_selected = 0;
+            }
+
+            _ => {}
+        }
+
+        // Persist current reading position to state
+        self.state.current_verse_index = self.current_verse_index;
+
+        self.check_reading_progress()?;
+        self.check_chapter_finished()?;
+        self.record_chapter_visit();
+        self.refresh_cross_references();
+        self.refresh_companion()?;
+        self.refresh_analytics();
+
+        Ok(())
+    }
+
+    /// Credit time since the last action to the session, ignoring idle gaps
+    fn track_reading_time(&mut self) {
+        let elapsed = self.last_activity.elapsed().min(IDLE_TIMEOUT);
+        self.last_activity = Instant::now();
+        self.history.add_time(elapsed.as_secs());
+    }
+
+    /// Edit a verse's note in the external editor and re-import it
+    ///
+    /// The caller must suspend the terminal first. Failures are reported in
+    /// the footer rather than ending the session.
+    pub fn edit_note_externally(&mut self, reference: &VerseReference) {
+        use crate::utils::editor::{edit_in_editor, note_document, note_from_document};
+
+        let verse_text = self
+            .current_chapter
+            .as_ref()
+            .and_then(|chapter| chapter.verses.iter().find(|v| &v.reference == reference))
+            .map(|verse| verse.text.clone())
+            .unwrap_or_default();
+        let note = self
+            .notes
+            .get(reference)
+            .map(|note| note.text.clone())
+            .unwrap_or_default();
+
+        let heading = format!("{} ({})", reference, self.translation);
+        let file_stem = format!("{}-{}-{}", reference.book, reference.chapter, reference.verse);
+
+        let result = edit_in_editor(&file_stem, &note_document(&heading, &verse_text, &note))
+            .and_then(|document| {
+                let edited = note_from_document(&document);
+                let message = if edited.is_empty() {
+                    format!("Note removed from {}", reference)
+                } else {
+                    format!("Note saved for {}", reference)
+                };
+                self.notes.set(reference.clone(), edited);
+                self.notes.save()?;
+                Ok(message)
+            });
+
+        self.status_message = Some(match result {
+            Ok(message) => message,
+            Err(err) => format!("{:#}", err),
+        });
+    }
+
+    /// Bookmarks in the current sort order, narrowed by the filter
+    pub fn visible_bookmarks(&self) -> Vec<&crate::config::Bookmark> {
+        let filter = self.bookmark_filter.trim().to_lowercase();
+        let mut bookmarks: Vec<_> = self
+            .bookmarks
+            .all()
+            .iter()
+            .filter(|bookmark| {
+                // "#tag" filters by tag only
+                if let Some(tag) = filter.strip_prefix('#') {
+                    return bookmark.tags.iter().any(|t| t.to_lowercase().starts_with(tag));
+                }
+
+                filter.is_empty()
+                    || bookmark.passage().to_string().to_lowercase().contains(&filter)
+                    || bookmark
+                        .collection
+                        .as_ref()
+                        .is_some_and(|name| name.to_lowercase().contains(&filter))
+                    || bookmark.tags.iter().any(|tag| tag.to_lowercase().contains(&filter))
+                    || bookmark
+                        .note
+                        .as_ref()
+                        .is_some_and(|note| note.to_lowercase().contains(&filter))
+                    || self
+                        .verse_previews
+                        .get(&bookmark.reference)
+                        .is_some_and(|text| text.to_lowercase().contains(&filter))
+            })
+            .collect();
+        self.bookmark_sort.sort(&mut bookmarks);
+        if self.bookmark_grouped {
+            // Stable sort keeps the chosen order within each collection; uncollected last
+            bookmarks.sort_by_key(|bookmark| match &bookmark.collection {
+                Some(name) => (false, name.to_lowercase()),
+                None => (true, String::new()),
+            });
+        }
+        bookmarks
+    }
+
+    /// Reference of the selected bookmark in the Bookmarks view
+    fn selected_bookmark(&self) -> Option<VerseReference> {
+        self.visible_bookmarks()
+            .get(self.bookmark_selected)
+            .map(|bookmark| bookmark.reference.clone())
+    }
+
+    /// Move the bookmark selection, keeping it within the visible list
+    fn move_bookmark_selection(&mut self, delta: isize) {
+        let count = self.visible_bookmarks().len();
+        if count == 0 {
+            self.bookmark_selected = 0;
+            return;
+        }
+        let index = self.bookmark_selected as isize + delta;
+        self.bookmark_selected = index.clamp(0, count as isize - 1) as usize;
+    }
+
+    /// Load verse text for list previews, skipping verses already loaded
+    fn load_verse_previews(&mut self, references: Vec<VerseReference>) -> Result<()> {
+        let Some(loader) = &self.loader else {
+            return Ok(());
+        };
+
+        for reference in references {
+            if self.verse_previews.contains_key(&reference) {
+                continue;
+            }
+            if let Some(verse) = loader.load_verse(&reference)? {
+                self.verse_previews.insert(reference, verse.text);
+            }
+        }
+        Ok(())
+    }
+
+    /// The verse under the Reader cursor
+    pub fn current_verse(&self) -> Option<&Verse> {
+        self.current_chapter
+            .as_ref()
+            .and_then(|chapter| chapter.verses.get(self.current_verse_index))
+    }
+
+    /// Keys while the word cursor is active: h/l move, v extends a phrase,
+    /// u/b/1-5 toggle underline, bold and colors, x clears, c opens the
+    /// concordance, Esc leaves
+    fn handle_word_action(&mut self, action: Action) -> Result<()> {
+        let Some(verse) = self.current_verse() else {
+            self.word_cursor = None;
+            return Ok(());
+        };
+        let reference = verse.reference.clone();
+        let words = word_ranges(&verse.text);
+        let translation = self.translation.clone();
+
+        let Some(cursor) = &mut self.word_cursor else {
+            return Ok(());
+        };
+        let last = words.len().saturating_sub(1);
+        let (first_word, last_word) = cursor.selected_words();
+        let (start, end) = match (words.get(first_word), words.get(last_word)) {
+            (Some(first), Some(last)) => (first.0, last.1),
+            _ => {
+                self.word_cursor = None;
+                return Ok(());
+            }
+        };
+
+        let style = match action {
+            Action::Char('u') => Some(WordStyle::Underline),
+            Action::Char('b') => Some(WordStyle::Bold),
+            Action::Char(c) if c.is_ascii_digit() => c
+                .to_digit(10)
+                .and_then(HighlightColor::from_digit)
+                .map(WordStyle::Color),
+            _ => None,
+        };
+
+        match action {
+            Action::PreviousVerse | Action::Char('h') => cursor.word = cursor.word.saturating_sub(1),
+            Action::NextVerse | Action::Char('l') | Action::Char('w') => {
+                cursor.word = (cursor.word + 1).min(last);
+            }
+            Action::GoToTop | Action::Char('0') => cursor.word = 0,
+            Action::GoToBottom | Action::Char('$') => cursor.word = last,
+            Action::Char('v') => {
+                cursor.anchor = match cursor.anchor {
+                    Some(_) => None,
+                    None => Some(cursor.word),
+                };
+            }
+            Action::Char('x') => {
+                cursor.anchor = None;
+                self.word_marks.clear(&reference, &translation, start, end);
+                self.word_marks.save()?;
+            }
+            Action::Enter => self.open_lexicon_popup(start, end)?,
+            Action::Char('c') => self.open_concordance(start, end)?,
+            Action::Escape => self.word_cursor = None,
+            Action::Quit => self.should_quit = true,
+            _ => {
+                if let Some(style) = style {
+                    cursor.anchor = None;
+                    self.word_marks.toggle(&reference, &translation, start, end, style);
+                    self.word_marks.save()?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Run the search view's query, grouping Strong's results by rendering
+    fn run_search(&mut self) -> Result<()> {
+        let Some(loader) = &self.loader else {
+            return Ok(());
+        };
+        if self.search_query.trim().is_empty() {
+            return Ok(());
+        }
+
+        let query = match SearchQuery::parse(&self.search_query, self.lexicon.as_ref()) {
+            Ok(query) => query,
+            Err(e) => {
+                self.status_message = Some(e.to_string());
+                return Ok(());
+            }
+        };
+        let verses = loader.search(&query, None, Some(self.settings.search_limit))?;
+
+        self.clear_search_results();
+        match &query {
+            SearchQuery::Text(_) => {
+                self.search_results = verses.iter().map(|verse| verse.reference.clone()).collect();
+            }
+            SearchQuery::Strongs(numbers) => {
+                for (rendering, references) in group_by_rendering(&verses, numbers) {
+                    self.search_groups.push((rendering, references.len()));
+                    self.search_results.extend(references);
+                }
+            }
+        }
+        for verse in verses {
+            self.verse_previews.insert(verse.reference, verse.text);
+        }
+
+        if self.search_results.is_empty() {
+            self.status_message = Some("No results".to_string());
+        }
+        Ok(())
+    }
+
+    fn clear_search_results(&mut self) {
+        self.search_results.clear();
+        self.search_groups.clear();
+        self.search_selected = 0;
+    }
+
+    /// Build the concordance view's word across the whole translation
+    fn build_concordance(&mut self) -> Result<()> {
+        let Some(loader) = &self.loader else {
+            return Ok(());
+        };
+        let word = self.concordance_query.trim().to_string();
+        if word.is_empty() {
+            return Ok(());
+        }
+
+        // Every verse containing the text; the concordance keeps whole words
+        let verses = loader.search(&SearchQuery::Text(word.clone()), None, None)?;
+        let concordance = Concordance::build(&word, &verses);
+        for verse in verses {
+            self.verse_previews.insert(verse.reference, verse.text);
+        }
+
+        if concordance.total() == 0 {
+            self.status_message = Some(format!("\"{}\" does not occur in {}", word, self.settings.translation));
+        }
+        self.concordance = Some(concordance);
+        self.concordance_selected = 0;
+        Ok(())
+    }
+
+    /// Open the concordance view for the chars `start..end` of the current verse
+    fn open_concordance(&mut self, start: usize, end: usize) -> Result<()> {
+        let Some(verse) = self.current_verse() else {
+            return Ok(());
+        };
+        self.concordance_query = verse.text.chars().skip(start).take(end - start).collect();
+        self.word_cursor = None;
+        self.view_mode = ViewMode::Concordance;
+        self.vim_normal_mode = false;
+        self.build_concordance()
+    }
+
+    /// Open the lexicon popup for the chars `start..end` of the current verse
+    fn open_lexicon_popup(&mut self, start: usize, end: usize) -> Result<()> {
+        let Some(verse) = self.current_verse() else {
+            return Ok(());
+        };
+        let word: String = verse.text.chars().skip(start).take(end - start).collect();
+        let numbers = strongs::strongs_at(&verse.tokens, start).to_vec();
+
+        if numbers.is_empty() {
+            self.status_message = Some(if verse.tokens.is_empty() {
+                format!("{} has no Strong's numbers", self.settings.translation)
+            } else {
+                format!("No Strong's number on \"{}\"", word)
+            });
+            return Ok(());
+        }
+
+        let mut entries = Vec::new();
+        for number in numbers {
+            let entry = match &self.lexicon {
+                Some(lexicon) => lexicon.get(&number)?,
+                None => None,
+            };
+            let count = match (self.strongs_counts.get(&number), &self.loader) {
+                (Some(count), _) => *count,
+                (None, Some(loader)) => {
+                    let count = loader.count_strongs(&number)?;
+                    self.strongs_counts.insert(number.clone(), count);
+                    count
+                }
+                (None, None) => 0,
+            };
+            entries.push((number, entry, count));
+        }
+
+        self.lexicon_popup = Some(LexiconPopup { word, entries });
+        Ok(())
+    }
+
+    /// Reload the cross-reference panel when the current verse changes
+    fn refresh_cross_references(&mut self) {
+        let current = self.current_verse().map(|verse| verse.reference.clone());
+        if !self.crossref_panel_open || current == self.crossref_verse {
+            return;
+        }
+
+        self.crossref_entries.clear();
+        self.crossref_selected = 0;
+        self.crossref_verse = current.clone();
+
+        let Some(current) = current else {
+            return;
+        };
+        match self.cross_reference_entries(&current) {
+            Ok(entries) => self.crossref_entries = entries,
+            Err(err) => {
+                self.status_message = Some(format!("Could not load cross-references: {}", err))
+            }
+        }
+    }
+
+    /// Cross-references from a verse with the text of each target
+    fn cross_reference_entries(&self, reference: &VerseReference) -> Result<Vec<(CrossReference, String)>> {
+        let (Some(index), Some(loader)) = (&self.crossrefs, &self.loader) else {
+            return Ok(Vec::new());
+        };
+
+        let mut entries = Vec::new();
+        for crossref in index.lookup(reference)?.into_iter().take(MAX_CROSS_REFERENCES) {
+            let preview = loader
+                .load_passage(&crossref.target)?
+                .iter()
+                .map(|verse| verse.text.as_str())
+                .collect::<Vec<_>>()
+                .join(" ");
+            entries.push((crossref, preview));
+        }
+        Ok(entries)
+    }
+
+    /// Move focus Reader -> outline -> cross-references -> companion ->
+    /// analytics -> Reader, skipping closed panels
+    fn cycle_panel_focus(&mut self) {
+        let open = [self.outline_open, self.crossref_panel_open, self.companion_open, self.analytics_open];
+        let focused = [self.outline_focus, self.crossref_focus, self.companion_focus, self.analytics_focus]
+            .iter()
+            .position(|focus| *focus);
+        let next = (focused.map_or(0, |idx| idx + 1)..open.len()).find(|&idx| open[idx]);
+        self.outline_focus = next == Some(0);
+        self.crossref_focus = next == Some(1);
+        self.companion_focus = next == Some(2);
+        self.analytics_focus = next == Some(3);
+    }
+
+    /// The module shown in the companion pane: the saved choice, or the
+    /// first installed
+    pub fn reference_module(&self) -> Option<&ModuleInfo> {
+        self.module_list
+            .iter()
+            .find(|module| self.settings.reference_module.as_ref() == Some(&module.id))
+            .or_else(|| self.module_list.first())
+    }
+
+    /// Show the next (or, with -1, previous) module in the companion pane
+    fn switch_reference_module(&mut self, step: isize) -> Result<()> {
+        if self.module_list.is_empty() {
+            return Ok(());
+        }
+        let current = self
+            .reference_module()
+            .and_then(|module| self.module_list.iter().position(|m| m.id == module.id))
+            .unwrap_or(0);
+        let next = (current as isize + step).rem_euclid(self.module_list.len() as isize) as usize;
+        self.settings.reference_module = Some(self.module_list[next].id.clone());
+        self.settings.save()?;
+        self.companion_verse = None;
+        Ok(())
+    }
+
+    /// Reload the companion pane when the current verse or module changes
+    fn refresh_companion(&mut self) -> Result<()> {
+        let current = self.current_verse().map(|verse| verse.reference.clone());
+        if !self.companion_open || current == self.companion_verse {
+            return Ok(());
+        }
+
+        self.companion_entries.clear();
+        self.companion_scroll = 0;
+        self.companion_verse = current.clone();
+
+        let (Some(store), Some(module), Some(current)) = (&self.modules, self.reference_module(), current)
+        else {
+            return Ok(());
+        };
+        self.companion_entries = store.entries_for(&module.id, &current)?;
+        Ok(())
+    }
+
+    /// Recount the analytics panel when the chapter, or with the book scope
+    /// the book, changes. Errors reading the translation are shown in the
+    /// status line rather than closing the app.
+    fn refresh_analytics(&mut self) {
+        let (Some(loader), Some(chapter)) = (&self.loader, &self.current_chapter) else {
+            return;
+        };
+        let current = (
+            chapter.book.clone(),
+            (!self.analytics_book).then_some(chapter.chapter_number),
+        );
+        if !self.analytics_open || Some(&current) == self.analytics_for.as_ref() {
+            return;
+        }
+
+        // The rest of the Bible is the baseline for distinctive words
+        if self.translation_counts.is_none() {
+            match loader.load_verses(None) {
+                Ok(verses) => self.translation_counts = Some(WordCounts::from_verses(&verses)),
+                Err(err) => {
+                    self.status_message = Some(format!("Could not count the translation: {}", err))
+                }
+            }
+        }
+
+        let stats = if self.analytics_book {
+            loader.load_verses(Some(&chapter.book)).map(|verses| WordCounts::from_verses(&verses))
+        } else {
+            Ok(WordCounts::from_verses(&chapter.verses))
+        };
+        let stats = match stats {
+            Ok(stats) => Some(stats),
+            Err(err) => {
+                self.status_message = Some(format!("Could not count {}: {}", chapter.book, err));
+                None
+            }
+        };
+
+        let owned = |words: Vec<(&str, usize)>| -> Vec<(String, usize)> {
+            words.into_iter().map(|(word, count)| (word.to_string(), count)).collect()
+        };
+        self.analytics_frequent = stats
+            .as_ref()
+            .map(|stats| owned(stats.frequent_words(ANALYTICS_WORDS)))
+            .unwrap_or_default();
+        self.analytics_distinctive = stats
+            .as_ref()
+            .zip(self.translation_counts.as_ref())
+            .map(|(stats, corpus)| owned(stats.distinctive_words(corpus, ANALYTICS_WORDS)))
+            .unwrap_or_default();
+        // Scope tabs, a blank line and four figures, then each word list
+        // under a blank line and a heading, with "None" when it's empty
+        self.analytics_lines = 6
+            + [&self.analytics_frequent, &self.analytics_distinctive]
+                .iter()
+                .map(|words| 2 + words.len().max(1) as u16)
+                .sum::<u16>();
+        self.analytics = stats;
+        self.analytics_scroll = 0;
+        self.analytics_for = Some(current);
+    }
+
+    /// Section headings in the current book, for the outline panel
+    pub fn outline(&self) -> Vec<&Heading> {
+        let Some(book) = &self.state.current_book else {
+            return Vec::new();
+        };
+        self.headings
+            .iter()
+            .filter(|heading| &heading.reference.book == book)
+            .collect()
+    }
+
+    /// Index in `outline()` of the section holding the current verse
+    pub fn current_section(&self) -> Option<usize> {
+        let current = &self.current_verse()?.reference;
+        self.outline().iter().rposition(|heading| {
+            (heading.reference.chapter, heading.reference.verse) <= (current.chapter, current.verse)
+        })
+    }
+
+    /// Section headings matching the go-to search, listed after the books
+    pub fn filtered_headings(&self) -> Vec<&Heading> {
+        // Short searches are for book names
+        if self.selector_search.chars().count() < 3 {
+            return Vec::new();
+        }
+        let search_lower = self.selector_search.to_lowercase();
+        self.headings
+            .iter()
+            .filter(|heading| heading.text.to_lowercase().contains(&search_lower))
+            .take(MAX_HEADING_MATCHES)
+            .collect()
+    }
+
+    /// Jump to the selected cross-reference, remembering where we were
+    fn follow_cross_reference(&mut self) -> Result<()> {
+        let Some(target) = self
+            .crossref_entries
+            .get(self.crossref_selected)
+            .map(|(crossref, _)| crossref.target.clone())
+        else {
+            return Ok(());
+        };
+
+        if let Some(current) = self.current_verse().map(|verse| verse.reference.clone()) {
+            self.back_stack.push(current);
+        }
+        let start = VerseReference::new(
+            target.book.clone(),
+            target.start_chapter,
+            target.start_verse.unwrap_or(1),
+        );
+        self.go_to_reference(&start)
+    }
+
+    /// Highlights shown in the Highlights view, in Bible order
+    pub fn visible_highlights(&self) -> Vec<&crate::config::highlights::Highlight> {
+        let mut highlights: Vec<_> = self
+            .highlights
+            .all()
+            .iter()
+            .filter(|h| self.highlight_filter.is_none_or(|color| h.color == color))
+            .collect();
+        highlights.sort_by_key(|h| {
+            (
+                crate::bible::book_name_to_id(&h.start.book).unwrap_or(u32::MAX),
+                h.start.chapter,
+                h.start.verse,
+            )
+        });
+        highlights
+    }
+
+    /// Move the Highlights view selection, keeping it in range
+    fn move_highlight_selection(&mut self, delta: isize) {
+        let count = self.visible_highlights().len();
+        if count == 0 {
+            self.highlight_selected = 0;
+            return;
+        }
+        let index = self.highlight_selected as isize + delta;
+        self.highlight_selected = index.clamp(0, count as isize - 1) as usize;
+    }
+
+    /// Apply a highlighter color (or clear with `None`) to the selected range,
+    /// or to the current verse when nothing is selected
+    fn apply_highlight(&mut self, color: Option<HighlightColor>) -> Result<()> {
+        let Some(current) = self
+            .current_chapter
+            .as_ref()
+            .and_then(|chapter| chapter.verses.get(self.current_verse_index))
+            .map(|verse| verse.reference.clone())
+        else {
+            return Ok(());
+        };
+
+        let start = self
+            .selection_anchor
+            .take()
+            .filter(|anchor| anchor.book == current.book)
+            .unwrap_or_else(|| current.clone());
+
+        match color {
+            Some(color) => self.highlights.add(start, current, color),
+            None => {
+                self.highlights.remove(&current);
+            }
+        }
+        self.highlights.save()
+    }
+
+    /// Jump the Reader to a verse
+    fn go_to_reference(&mut self, reference: &VerseReference) -> Result<()> {
+        self.state.current_book = Some(reference.book.clone());
+        self.state.current_chapter = Some(reference.chapter);
+        self.state.current_verse_index = reference.verse.saturating_sub(1) as usize;
+        self.load_current_chapter()?;
+        self.scroll_offset = 0;
+        self.adjust_scroll_for_current_verse();
+        Ok(())
+    }
+
+    /// Mark the chapter read once the Reader reaches its last verse
+    fn check_chapter_finished(&mut self) -> Result<()> {
+        if self.view_mode != ViewMode::Reader {
+            return Ok(());
+        }
+        let Some(chapter) = &self.current_chapter else {
+            return Ok(());
+        };
+
+        let position = (chapter.book.clone(), chapter.chapter_number, self.current_verse_index);
+        if self.last_read_position.as_ref() == Some(&position) {
+            return Ok(());
+        }
+
+        let at_end = !chapter.verses.is_empty()
+            && self.current_verse_index + 1 >= chapter.verses.len();
+        if at_end && self.read_chapters.mark_read(&chapter.book, chapter.chapter_number) {
+            self.read_chapters.save()?;
+        }
+        self.last_read_position = Some(position);
+        Ok(())
+    }
+
+    /// Move the coverage map cursor by books and chapters, keeping it in range
+    fn move_coverage_cursor(&mut self, books: isize, chapters: i32) {
+        use crate::bible::BOOK_ORDER;
+
+        let book = self.coverage_book as isize + books;
+        self.coverage_book = book.clamp(0, BOOK_ORDER.len() as isize - 1) as usize;
+
+        let (_, _, _, chapter_count) = BOOK_ORDER[self.coverage_book];
+        let chapter = self.coverage_chapter as i32 + chapters;
+        self.coverage_chapter = chapter.clamp(1, chapter_count as i32) as u32;
+    }
+
+    /// Log the chapter on screen in the reading history
+    fn record_chapter_visit(&mut self) {
+        if let Some(chapter) = &self.current_chapter {
+            self.history.record_chapter(&chapter.book, chapter.chapter_number);
+        }
+    }
+
+    /// The plan currently being followed, if any
+    pub fn active_plan(&self) -> Option<&ReadingPlan> {
+        let plan_id = self.plan_progress.plan_id.as_deref()?;
+        self.plans.iter().find(|plan| plan.id == plan_id)
+    }
+
+    /// Outstanding readings for today's date, oldest first, as (day, index)
+    pub fn outstanding_readings(&self) -> Vec<(usize, usize)> {
+        match self.active_plan() {
+            Some(plan) => self
+                .plan_progress
+                .outstanding(plan, chrono::Local::now().date_naive()),
+            None => Vec::new(),
+        }
+    }
+
+    /// Number of selectable rows in the reading plan panel
+    fn plan_item_count(&self) -> usize {
+        if self.plan_picking {
+            self.plans.len()
+        } else {
+            // Outstanding readings plus "Change plan"
+            self.outstanding_readings().len() + 1
+        }
+    }
+
+    /// Handle Enter in the reading plan panel
+    fn handle_plan_select(&mut self) -> Result<()> {
+        if self.plan_picking {
+            if let Some(plan) = self.plans.get(self.plan_index) {
+                let plan_id = plan.id.clone();
+                self.plan_progress.start(&plan_id, chrono::Local::now().date_naive());
+                self.plan_progress.save()?;
+                self.active_reading = None;
+                self.plan_picking = false;
+                self.plan_index = 0;
+            }
+            return Ok(());
+        }
+
+        let outstanding = self.outstanding_readings();
+        match outstanding.get(self.plan_index) {
+            Some(&(day, index)) => {
+                let passage = self
+                    .active_plan()
+                    .and_then(|plan| plan.days.get(day - 1)?.get(index).cloned());
+                if let Some(passage) = passage {
+                    self.open_passage(&passage)?;
+                    self.active_reading = Some(ActiveReading { day, index, passage });
+                    self.plan_open = false;
+                }
+            }
+            None => {
+                // "Change plan" row
+                self.plan_picking = true;
+                self.plan_index = self
+                    .plan_progress
+                    .plan_id
+                    .as_deref()
+                    .and_then(|id| self.plans.iter().position(|plan| plan.id == id))
+                    .unwrap_or(0);
+            }
+        }
+        Ok(())
+    }
+
+    /// Jump the Reader to the start of a passage
+    fn open_passage(&mut self, passage: &Passage) -> Result<()> {
+        self.state.current_book = Some(passage.book.clone());
+        self.state.current_chapter = Some(passage.start_chapter);
+        self.state.current_verse_index = passage.start_verse.unwrap_or(1).saturating_sub(1) as usize;
+        self.load_current_chapter()?;
+        self.scroll_offset = 0;
+        self.adjust_scroll_for_current_verse();
+        Ok(())
+    }
+
+    /// Mark the active plan reading done once the Reader reaches its last verse
+    fn check_reading_progress(&mut self) -> Result<()> {
+        let Some(reading) = &self.active_reading else {
+            return Ok(());
+        };
+        let Some(verse) = self
+            .current_chapter
+            .as_ref()
+            .and_then(|chapter| chapter.verses.get(self.current_verse_index))
+        else {
+            return Ok(());
+        };
+        let Some(last) = reading.passage.last_verse() else {
+            return Ok(());
+        };
+
+        let reached_end = verse.reference.book == last.book
+            && (verse.reference.chapter, verse.reference.verse) >= (last.chapter, last.verse);
+
+        if reached_end {
+            let (day, index) = (reading.day, reading.index);
+            if self.plan_progress.complete(day, index) {
+                self.plan_progress.save()?;
+            }
+            self.active_reading = None;
+        }
         Ok(())
     }
 
@@ -471,6 +2307,10 @@ impl App {
         self.state.save()?;
         self.settings.save()?;
         self.bookmarks.save()?;
+        self.plan_progress.save()?;
+        self.history.save()?;
+        self.read_chapters.save()?;
+        self.notes.save()?;
         Ok(())
     }
 
@@ -535,32 +2375,25 @@ impl App {
 
     /// Handle selector navigation down (j key)
     fn handle_selector_down(&mut self) -> Result<()> {
-        match self.selector_step {
-            SelectorStep::Book => {
-                let filtered_books = self.get_filtered_books();
-                if !filtered_books.is_empty() {
-                    self.selector_index = (self.selector_index + 1).min(filtered_books.len() - 1);
-                }
-            }
-            SelectorStep::Chapter => {
-                if let Some(book) = &self.selector_selected_book {
-                    let chapter_count = crate::bible::get_chapter_count(book);
-                    if self.selector_index + 1 < chapter_count as usize {
-                        self.selector_index += 1;
-                    }
-                }
-            }
-            SelectorStep::Verse => {
-                if let Some(chapter) = &self.current_chapter {
-                    if self.selector_index + 1 < chapter.verses.len() {
-                        self.selector_index += 1;
-                    }
-                }
-            }
+        if self.selector_index + 1 < self.selector_item_count() {
+            self.selector_index += 1;
         }
         Ok(())
     }
 
+    /// Number of entries in the current selector step
+    fn selector_item_count(&self) -> usize {
+        let book = self.selector_selected_book.as_deref().unwrap_or_default();
+        match self.selector_step {
+            SelectorStep::Book => self.get_filtered_books().len() + self.filtered_headings().len(),
+            SelectorStep::Chapter => crate::bible::get_chapter_count(book) as usize,
+            SelectorStep::Verse => self
+                .selector_selected_chapter
+                .and_then(|chapter| verse_count(book, chapter))
+                .unwrap_or(0) as usize,
+        }
+    }
+
     /// Handle selector navigation up (k key)
     fn handle_selector_up(&mut self) -> Result<()> {
         if self.selector_index > 0 {
@@ -604,20 +2437,24 @@ impl App {
                     self.selector_step = SelectorStep::Chapter;
                     self.selector_index = 0;
                     self.selector_search.clear();
+                    return Ok(());
+                }
+
+                // Headings follow the books and jump straight to their verse
+                let heading = self
+                    .filtered_headings()
+                    .get(self.selector_index - filtered_books.len())
+                    .map(|heading| heading.reference.clone());
+                if let Some(reference) = heading {
+                    self.go_to_reference(&reference)?;
+                    self.selector_open = false;
+                    self.selector_search.clear();
                 }
             }
             SelectorStep::Chapter => {
                 // Select chapter and move to verse selection
                 let selected_chapter = (self.selector_index + 1) as u32;
                 self.selector_selected_chapter = Some(selected_chapter);
-
-                // Load the chapter so we can show verse count
-                if let (Some(loader), Some(book)) = (&self.loader, &self.selector_selected_book) {
-                    if let Ok(chapter) = loader.load_chapter(book, selected_chapter) {
-                        self.current_chapter = Some(chapter);
-                    }
-                }
-
                 self.selector_step = SelectorStep::Verse;
                 self.selector_index = 0;
             }
@@ -627,11 +2464,17 @@ impl App {
                     &self.selector_selected_book,
                     self.selector_selected_chapter,
                 ) {
+                    let reference =
+                        VerseReference::new(book.clone(), chapter, (self.selector_index + 1) as u32);
+                    if !is_valid_reference(&reference) {
+                        return Ok(());
+                    }
+
                     self.state.current_book = Some(book.clone());
                     self.state.current_chapter = Some(chapter);
-                    self.current_verse_index = self.selector_index;
+                    self.state.current_verse_index = self.selector_index;
 
-                    // Ensure chapter is loaded
+                    // Load the chapter, which picks up the selected verse index
                     self.load_current_chapter()?;
 
                     // Reset scroll to top of chapter, then adjust for selected verse
@@ -0,0 +1,171 @@
+use super::Verse;
+use std::collections::HashMap;
+
+/// Average silent reading speed used for reading time estimates
+pub const WORDS_PER_MINUTE: usize = 200;
+
+/// Function words, including the archaic forms of older English
+/// translations, left out of the frequent and distinctive word lists
+const STOP_WORDS: &[&str] = &[
+    "a", "about", "after", "against", "all", "also", "am", "an", "and", "any", "are", "art",
+    "as", "at", "be", "because", "been", "before", "being", "but", "by", "came", "can",
+    "canst", "come", "cometh", "could", "did", "didst", "do", "doeth", "dost", "doth", "even",
+    "every", "for", "from", "had", "hadst", "has", "hast", "hath", "have", "he", "hence",
+    "her", "hers", "herself", "him", "himself", "his", "hither", "how", "i", "if", "in",
+    "into", "is", "it", "its", "itself", "let", "lo", "may", "mayest", "me", "mine", "my",
+    "myself", "nay", "neither", "no", "nor", "not", "now", "o", "of", "oh", "on", "one",
+    "or", "our", "ours", "ourselves", "out", "over", "said", "saith", "say", "saying",
+    "shall", "shalt", "she", "should", "shouldest", "so", "some", "such", "than", "that",
+    "the", "thee", "their", "theirs", "them", "themselves", "then", "thence", "there",
+    "thereby", "therefore", "therein", "thereof", "these", "they", "thine", "this",
+    "thither", "those", "thou", "thus", "thy", "thyself", "to", "unto", "up", "upon", "us",
+    "very", "was", "wast", "we", "went", "were", "wert", "what", "when", "whence", "where",
+    "wherefore", "wherein", "whereof", "which", "while", "whither", "who", "whom", "whose",
+    "why", "will", "wilt", "with", "would", "wouldest", "ye", "yea", "yet", "you", "your",
+    "yours", "yourselves",
+];
+
+/// Lowercased words of a verse's text, with punctuation and numbers dropped
+pub fn words(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphabetic() && c != '\'' && c != '’')
+        .map(|word| word.trim_matches(|c| c == '\'' || c == '’'))
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+}
+
+/// Whether a word is too common to say anything about a passage
+pub fn is_stop_word(word: &str) -> bool {
+    STOP_WORDS.binary_search(&word).is_ok()
+}
+
+/// Word and verse counts for a chapter, a book or a whole translation
+#[derive(Debug, Clone, Default)]
+pub struct WordCounts {
+    pub verses: usize,
+    pub words: usize,
+    /// Occurrences of each lowercased word
+    pub counts: HashMap<String, usize>,
+}
+
+impl WordCounts {
+    /// Count the words of some verses
+    pub fn from_verses(verses: &[Verse]) -> Self {
+        let mut stats = Self {
+            verses: verses.len(),
+            ..Self::default()
+        };
+        for verse in verses {
+            for word in words(&verse.text) {
+                stats.words += 1;
+                *stats.counts.entry(word).or_default() += 1;
+            }
+        }
+        stats
+    }
+
+    /// Estimated minutes to read the text, at least one for any text
+    pub fn reading_minutes(&self) -> usize {
+        self.words.div_ceil(WORDS_PER_MINUTE)
+    }
+
+    /// The most frequent content words, ties in alphabetical order
+    pub fn frequent_words(&self, limit: usize) -> Vec<(&str, usize)> {
+        let mut words: Vec<(&str, usize)> = self
+            .counts
+            .iter()
+            .filter(|(word, _)| !is_stop_word(word))
+            .map(|(word, count)| (word.as_str(), *count))
+            .collect();
+        words.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        words.truncate(limit);
+        words
+    }
+
+    /// Content words used more here than in the rest of `corpus`, which
+    /// includes this text, most distinctive first
+    ///
+    /// Words are scored by log-likelihood (G²) and must occur at least
+    /// twice, so one-off names don't crowd out the passage's vocabulary.
+    pub fn distinctive_words(&self, corpus: &WordCounts, limit: usize) -> Vec<(&str, usize)> {
+        let rest_words = corpus.words.saturating_sub(self.words);
+        if self.words == 0 || rest_words == 0 {
+            return Vec::new();
+        }
+        let (here_total, rest_total) = (self.words as f64, rest_words as f64);
+
+        let mut scored: Vec<(&str, usize, f64)> = self
+            .counts
+            .iter()
+            .filter(|(word, count)| **count >= 2 && !is_stop_word(word))
+            .filter_map(|(word, &count)| {
+                let rest = corpus.counts.get(word).copied().unwrap_or(0).saturating_sub(count);
+                let (here, rest) = (count as f64, rest as f64);
+                if here / here_total <= rest / rest_total {
+                    return None;
+                }
+
+                let expected_here = here_total * (here + rest) / (here_total + rest_total);
+                let expected_rest = rest_total * (here + rest) / (here_total + rest_total);
+                let mut score = here * (here / expected_here).ln();
+                if rest > 0.0 {
+                    score += rest * (rest / expected_rest).ln();
+                }
+                Some((word.as_str(), count, 2.0 * score))
+            })
+            .collect();
+
+        scored.sort_by(|a, b| b.2.total_cmp(&a.2).then(a.0.cmp(b.0)));
+        scored
+            .into_iter()
+            .take(limit)
+            .map(|(word, count, _)| (word, count))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bible::VerseReference;
+
+    fn verses(texts: &[&str]) -> Vec<Verse> {
+        texts
+            .iter()
+            .enumerate()
+            .map(|(idx, text)| Verse::from_text(VerseReference::new("Gen", 1, idx as u32 + 1), text.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_stop_words_sorted() {
+        assert!(STOP_WORDS.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_word_counts() {
+        let stats = WordCounts::from_verses(&verses(&[
+            "And God said, Let there be light: and there was light.",
+            "And God saw the light, that it was good.",
+        ]));
+        assert_eq!(stats.verses, 2);
+        assert_eq!(stats.words, 20);
+        assert_eq!(stats.reading_minutes(), 1);
+        assert_eq!(stats.frequent_words(3), vec![("light", 3), ("god", 2), ("good", 1)]);
+    }
+
+    #[test]
+    fn test_distinctive_words() {
+        let chapter = WordCounts::from_verses(&verses(&["The ram of consecration, the ram of the LORD."]));
+        let mut corpus = WordCounts::from_verses(&verses(&[
+            "The LORD is my shepherd.",
+            "The LORD is good, the LORD is kind.",
+        ]));
+        corpus.words += chapter.words;
+        for (word, count) in &chapter.counts {
+            *corpus.counts.entry(word.clone()).or_default() += count;
+        }
+
+        // "lord" is common elsewhere and "consecration" occurs once
+        assert_eq!(chapter.distinctive_words(&corpus, 5), vec![("ram", 2)]);
+    }
+}
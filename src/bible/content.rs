@@ -0,0 +1,579 @@
+use super::Verse;
+use serde::{Deserialize, Serialize};
+
+/// Formatting a translation applies to a run of verse text
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextStyle {
+    /// Words of Jesus, shown in red letters
+    #[serde(default)]
+    pub words_of_jesus: bool,
+    /// Words supplied by the translators, traditionally in italics
+    #[serde(default)]
+    pub supplied: bool,
+    /// The divine name (LORD), shown in small caps
+    #[serde(default)]
+    pub divine_name: bool,
+    /// The musical interlude "Selah" in the Psalms and Habakkuk
+    #[serde(default)]
+    pub selah: bool,
+}
+
+impl TextStyle {
+    fn is_plain(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Styled chars `start..end` of a verse's plain text
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Segment {
+    pub start: usize,
+    pub end: usize,
+    pub style: TextStyle,
+}
+
+/// What a note attached to a verse holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NoteKind {
+    Footnote,
+    CrossReference,
+}
+
+/// A line of poetry within a verse
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PoetryLine {
+    /// Char offset in the plain text where the line starts
+    pub start: usize,
+    /// Indentation level, 1 for the first level
+    pub level: u8,
+}
+
+/// What a title shown above a verse is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TitleKind {
+    /// Psalm superscription, e.g. "A Psalm of David."
+    Superscription,
+    /// Hebrew letter heading a stanza of an acrostic, as in Psalm 119
+    Acrostic,
+    /// Section heading (pericope title), e.g. "The Sermon on the Mount"
+    Section,
+}
+
+/// A title shown above a verse
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Title {
+    pub kind: TitleKind,
+    pub text: String,
+}
+
+/// A footnote or cross-reference note anchored in a verse
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Note {
+    /// Char offset in the plain text the marker follows
+    pub offset: usize,
+    pub kind: NoteKind,
+    pub text: String,
+}
+
+/// Formatting and notes parsed out of a verse's source text
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Content {
+    /// The source marks a new paragraph starting with this verse
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub paragraph: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub segments: Vec<Segment>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notes: Vec<Note>,
+    /// Poetry lines, for verses set as poetry
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub lines: Vec<PoetryLine>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub titles: Vec<Title>,
+}
+
+impl Content {
+    pub fn is_empty(&self) -> bool {
+        !self.paragraph
+            && self.segments.is_empty()
+            && self.notes.is_empty()
+            && self.lines.is_empty()
+            && self.titles.is_empty()
+    }
+
+    /// Style of the char at `offset`; segments are sorted by start, and a
+    /// later one nested inside another (like Selah) wins
+    pub fn style_at(&self, offset: usize) -> TextStyle {
+        self.segments
+            .iter()
+            .rev()
+            .find(|segment| segment.start <= offset && offset < segment.end)
+            .map(|segment| segment.style)
+            .unwrap_or_default()
+    }
+}
+
+/// Split formatting markup out of verse source text
+///
+/// Understands OSIS (`<q who="Jesus">`, `<transChange type="added">`,
+/// `<divineName>`, `<note>`, `<p>`, `<l level>`, `<title type="psalm">`,
+/// `<title>`), USFM (`\wj`, `\add`, `\nd`, `\f`, `\x`, `\p`, `\q1`, `\qs`,
+/// `\d`, `\qa`, `\s1`, `\ms`) and the Bible SuperSearch convention of `‹red letters›`,
+/// `[italics]` and `¶` paragraphs. Paragraph marks only count before any text.
+/// Returns the text with that markup removed, but any Strong's tags left in
+/// place, and the content with offsets into the fully plain text.
+pub fn parse(text: &str) -> (String, Content) {
+    let mut parser = Parser::default();
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        if let Some(len) = parser.markup(rest) {
+            rest = &rest[len..];
+            continue;
+        }
+
+        parser.fragment.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+
+    parser.flush();
+    let mut content = Content {
+        paragraph: parser.paragraph,
+        segments: parser.segments,
+        notes: parser.notes,
+        lines: parser.lines,
+        titles: parser.titles,
+    };
+    content.segments.retain(|segment| !segment.style.is_plain());
+    (parser.output, content)
+}
+
+#[derive(Default)]
+struct Parser {
+    /// Text with formatting removed so far
+    output: String,
+    /// Text since the style last changed
+    fragment: String,
+    /// Length of `output` once Strong's tags are removed
+    plain_len: usize,
+    style: TextStyle,
+    /// Whether each open `<q>` / `<transChange>` set a style
+    open_quotes: Vec<bool>,
+    open_changes: Vec<bool>,
+    segments: Vec<Segment>,
+    notes: Vec<Note>,
+    paragraph: bool,
+    lines: Vec<PoetryLine>,
+    titles: Vec<Title>,
+}
+
+impl Parser {
+    /// Handle markup at the start of `rest`, returning its length
+    fn markup(&mut self, rest: &str) -> Option<usize> {
+        match rest.chars().next()? {
+            '‹' => self.set(|style| style.words_of_jesus = true),
+            '›' => self.set(|style| style.words_of_jesus = false),
+            '¶' => {
+                self.paragraph_mark();
+                let len = '¶'.len_utf8();
+                return Some(if rest[len..].starts_with(' ') { len + 1 } else { len });
+            }
+            '[' => self.set(|style| style.supplied = true),
+            ']' => self.set(|style| style.supplied = false),
+            '<' => return self.osis(rest),
+            '\\' => return self.usfm(rest),
+            _ => return None,
+        }
+        Some(rest.chars().next()?.len_utf8())
+    }
+
+    fn osis(&mut self, rest: &str) -> Option<usize> {
+        let end = rest.find('>')? + 1;
+        let tag = &rest[..end];
+        let name = tag[1..end - 1]
+            .trim_end_matches('/')
+            .split_whitespace()
+            .next()
+            .unwrap_or("");
+
+        match name {
+            "q" if tag.contains("eID") => {
+                // Closing milestone of a quotation split across verses
+                self.set(|style| style.words_of_jesus = false);
+            }
+            "q" => {
+                let jesus = tag.contains("who=\"Jesus\"");
+                if jesus {
+                    self.set(|style| style.words_of_jesus = true);
+                }
+                if !tag.ends_with("/>") {
+                    self.open_quotes.push(jesus);
+                }
+            }
+            "/q" => {
+                if self.open_quotes.pop() == Some(true) {
+                    self.set(|style| style.words_of_jesus = false);
+                }
+            }
+            "transChange" => {
+                let added = tag.contains("type=\"added\"");
+                if added {
+                    self.set(|style| style.supplied = true);
+                }
+                self.open_changes.push(added);
+            }
+            "/transChange" => {
+                if self.open_changes.pop() == Some(true) {
+                    self.set(|style| style.supplied = false);
+                }
+            }
+            "p" => self.paragraph_mark(),
+            "/p" | "lg" | "/lg" | "/l" => {}
+            "milestone" if tag.contains("x-p") => self.paragraph_mark(),
+            "l" => {
+                let level = attribute(tag, "level").and_then(|level| level.parse().ok());
+                self.poetry_line(level.unwrap_or(1));
+            }
+            "title" => {
+                let kind = match attribute(tag, "type") {
+                    Some("psalm") => TitleKind::Superscription,
+                    Some("acrostic") => TitleKind::Acrostic,
+                    None | Some("section" | "main") => TitleKind::Section,
+                    // Other titles are left in the text
+                    _ => return None,
+                };
+                let body_end = rest.find("</title>").unwrap_or(rest.len());
+                self.title(kind, strip_tags(&rest[end..body_end.max(end)]));
+                return Some((body_end + "</title>".len()).min(rest.len()));
+            }
+            "divineName" => self.set(|style| style.divine_name = true),
+            "/divineName" => self.set(|style| style.divine_name = false),
+            "note" => {
+                let body_end = rest.find("</note>").unwrap_or(rest.len());
+                let kind = if tag.contains("crossReference") {
+                    NoteKind::CrossReference
+                } else {
+                    NoteKind::Footnote
+                };
+                self.note(kind, strip_tags(&rest[end..body_end.max(end)]));
+                return Some((body_end + "</note>".len()).min(rest.len()));
+            }
+            // Strong's word elements and tags are for the Strong's parser
+            _ => return None,
+        }
+        Some(end)
+    }
+
+    fn usfm(&mut self, rest: &str) -> Option<usize> {
+        let marker: String = rest[1..]
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric() || *c == '*' || *c == '+')
+            .collect();
+        // A space after an opening marker belongs to the marker
+        let mut len = 1 + marker.len();
+        if !marker.ends_with('*') && rest[len..].starts_with(' ') {
+            len += 1;
+        }
+
+        match marker.trim_start_matches('+') {
+            "wj" => self.set(|style| style.words_of_jesus = true),
+            "wj*" => self.set(|style| style.words_of_jesus = false),
+            "add" => self.set(|style| style.supplied = true),
+            "add*" => self.set(|style| style.supplied = false),
+            "p" | "b" => self.paragraph_mark(),
+            "q" | "q1" | "qm" | "qm1" => self.poetry_line(1),
+            "q2" | "qm2" => self.poetry_line(2),
+            "q3" | "qm3" => self.poetry_line(3),
+            "q4" => self.poetry_line(4),
+            "qs" => self.set(|style| style.selah = true),
+            "qs*" => self.set(|style| style.selah = false),
+            "d" | "qa" | "s" | "s1" | "s2" | "s3" | "ms" | "ms1" | "ms2" => {
+                // Titles run to the next marker
+                let body_end = rest[len..].find('\\').map(|pos| len + pos).unwrap_or(rest.len());
+                let kind = match marker.as_str() {
+                    "d" => TitleKind::Superscription,
+                    "qa" => TitleKind::Acrostic,
+                    _ => TitleKind::Section,
+                };
+                self.title(kind, rest[len..body_end].trim().to_string());
+                return Some(body_end);
+            }
+            "nd" => self.set(|style| style.divine_name = true),
+            "nd*" => self.set(|style| style.divine_name = false),
+            "f" | "x" => {
+                let close = format!("\\{}*", marker);
+                let body_end = rest.find(&close).unwrap_or(rest.len());
+                let kind = if marker == "x" { NoteKind::CrossReference } else { NoteKind::Footnote };
+                self.note(kind, usfm_note_text(&rest[len.min(body_end)..body_end]));
+                return Some((body_end + close.len()).min(rest.len()));
+            }
+            _ => return None,
+        }
+        Some(len)
+    }
+
+    /// Start a poetry line, separated from the previous one by a space
+    fn poetry_line(&mut self, level: u8) {
+        self.flush();
+        if self.plain_len > 0 && !self.output.ends_with(char::is_whitespace) {
+            self.fragment.push(' ');
+            self.flush();
+        }
+        match self.lines.last_mut() {
+            Some(last) if last.start == self.plain_len => last.level = level,
+            _ => self.lines.push(PoetryLine {
+                start: self.plain_len,
+                level,
+            }),
+        }
+    }
+
+    fn title(&mut self, kind: TitleKind, text: String) {
+        if !text.is_empty() {
+            self.titles.push(Title { kind, text });
+        }
+    }
+
+    fn paragraph_mark(&mut self) {
+        if self.plain_len == 0 && plain_len(&self.fragment) == 0 {
+            self.paragraph = true;
+        }
+    }
+
+    /// Change the style, closing the current segment
+    fn set(&mut self, change: impl FnOnce(&mut TextStyle)) {
+        self.flush();
+        change(&mut self.style);
+    }
+
+    fn note(&mut self, kind: NoteKind, text: String) {
+        self.flush();
+        if !text.is_empty() {
+            self.notes.push(Note {
+                offset: self.plain_len,
+                kind,
+                text,
+            });
+        }
+    }
+
+    fn flush(&mut self) {
+        if self.fragment.is_empty() {
+            return;
+        }
+
+        let len = plain_len(&self.fragment);
+        if len > 0 {
+            let start = self.plain_len;
+            match self.segments.last_mut() {
+                Some(last) if last.end == start && last.style == self.style => last.end += len,
+                _ => self.segments.push(Segment {
+                    start,
+                    end: start + len,
+                    style: self.style,
+                }),
+            }
+        }
+        self.plain_len += len;
+        self.output.push_str(&std::mem::take(&mut self.fragment));
+    }
+}
+
+/// Mark each "Selah" in a verse's plain text, for translations that don't tag it
+pub fn mark_selah(text: &str, content: &mut Content) {
+    let chars: Vec<char> = text.chars().collect();
+    let word: Vec<char> = "Selah".chars().collect();
+
+    for start in 0..chars.len().saturating_sub(word.len() - 1) {
+        let end = start + word.len();
+        let bounded = (start == 0 || !chars[start - 1].is_alphanumeric())
+            && chars.get(end).is_none_or(|c| !c.is_alphanumeric());
+        if chars[start..end] == word[..] && bounded && !content.style_at(start).selah {
+            content.segments.push(Segment {
+                start,
+                end,
+                style: TextStyle {
+                    selah: true,
+                    ..content.style_at(start)
+                },
+            });
+        }
+    }
+    content.segments.sort_by_key(|segment| segment.start);
+}
+
+/// Acrostic heading for a verse of Psalm 119, which gives each letter of the
+/// Hebrew alphabet eight verses
+pub fn psalm_119_acrostic(verse: u32) -> Option<String> {
+    const LETTERS: [(&str, &str); 22] = [
+        ("א", "ALEPH"), ("ב", "BETH"), ("ג", "GIMEL"), ("ד", "DALETH"), ("ה", "HE"),
+        ("ו", "VAU"), ("ז", "ZAIN"), ("ח", "CHETH"), ("ט", "TETH"), ("י", "JOD"),
+        ("כ", "CAPH"), ("ל", "LAMED"), ("מ", "MEM"), ("נ", "NUN"), ("ס", "SAMECH"),
+        ("ע", "AIN"), ("פ", "PE"), ("צ", "TZADDI"), ("ק", "KOPH"), ("ר", "RESH"),
+        ("ש", "SCHIN"), ("ת", "TAU"),
+    ];
+
+    if verse == 0 || !(verse - 1).is_multiple_of(8) {
+        return None;
+    }
+    let (letter, name) = LETTERS.get((verse as usize - 1) / 8)?;
+    Some(format!("{} {}", letter, name))
+}
+
+/// Value of an XML attribute in a tag, e.g. `level` in `<l level="2">`
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let pattern = format!("{}=\"", name);
+    let start = tag.find(&pattern)? + pattern.len();
+    let len = tag[start..].find('"')?;
+    Some(&tag[start..start + len])
+}
+
+/// Which verses of a chapter start a paragraph
+///
+/// Uses the translation's paragraph marks when the chapter has any.
+/// Otherwise breaks after a verse that ends a sentence, once the paragraph
+/// has a few verses, so unmarked texts still read as prose.
+pub fn paragraph_starts(verses: &[Verse]) -> Vec<bool> {
+    const MIN_HEURISTIC_VERSES: usize = 4;
+
+    if verses.iter().any(|verse| verse.content.paragraph) {
+        return verses
+            .iter()
+            .enumerate()
+            .map(|(idx, verse)| idx == 0 || verse.content.paragraph)
+            .collect();
+    }
+
+    let mut starts = Vec::with_capacity(verses.len());
+    let mut length = 0;
+    let mut previous_ends_sentence = false;
+    for verse in verses {
+        let start = starts.is_empty() || (previous_ends_sentence && length >= MIN_HEURISTIC_VERSES);
+        if start {
+            length = 0;
+        }
+        starts.push(start);
+        length += 1;
+
+        let end = verse.text.trim_end().trim_end_matches(['"', '\'', '’', '”', ')']);
+        previous_ends_sentence = end.ends_with(['.', '?', '!']);
+    }
+    starts
+}
+
+/// Length in chars of text once Strong's tags and leftover markup are removed
+fn plain_len(text: &str) -> usize {
+    if text.contains(['<', '{']) {
+        super::strongs::plain_text(&super::strongs::parse_tagged(text)).chars().count()
+    } else {
+        text.chars().count()
+    }
+}
+
+fn strip_tags(text: &str) -> String {
+    super::strongs::plain_text(&super::strongs::parse_tagged(text)).trim().to_string()
+}
+
+/// Readable text of a USFM note body: `+ \fr 1:1 \ft Or, In the beginning`
+fn usfm_note_text(body: &str) -> String {
+    let mut text = String::new();
+    // The caller (+, -, or a letter) comes first
+    let body = body.trim_start();
+    let body = body.split_once(' ').map(|(_, rest)| rest).unwrap_or("");
+
+    for part in body.split('\\') {
+        let (marker, value) = part.split_once(' ').unwrap_or((part, ""));
+        // The origin reference repeats the verse being annotated
+        if marker != "fr" && marker != "xo" {
+            text.push_str(value);
+            text.push(' ');
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bible::VerseReference;
+
+    #[test]
+    fn test_parse_osis_usfm_and_supersearch() {
+        let (text, content) = parse(
+            r#"<q who="Jesus">Verily I say</q> unto <transChange type="added">thee</transChange>,<note type="x">Or, truly</note> the <divineName>Lord</divineName>"#,
+        );
+        assert_eq!(text, "Verily I say unto thee, the Lord");
+        assert!(content.style_at(0).words_of_jesus);
+        assert!(!content.style_at(13).words_of_jesus);
+        assert!(content.style_at(18).supplied);
+        assert!(content.style_at(30).divine_name);
+        assert_eq!(content.notes, vec![Note { offset: 23, kind: NoteKind::Footnote, text: "Or, truly".to_string() }]);
+
+        let (text, content) = parse(r"\wj I am\wj* the \nd Lord\nd*\x - \xo 1:1 \xt John 8:58\x*");
+        assert_eq!(text, "I am the Lord");
+        assert!(content.style_at(2).words_of_jesus);
+        assert!(content.style_at(10).divine_name);
+        assert_eq!(content.notes[0].kind, NoteKind::CrossReference);
+        assert_eq!(content.notes[0].text, "John 8:58");
+        assert_eq!(content.notes[0].offset, 13);
+
+        // Offsets skip Strong's tags, which are left for the Strong's parser
+        let (text, content) = parse("And God<H430> [was] ‹good›");
+        assert_eq!(text, "And God<H430> was good");
+        assert!(content.style_at(9).supplied);
+        assert!(content.style_at(12).words_of_jesus);
+
+        assert!(parse("In the beginning").1.is_empty());
+    }
+
+    #[test]
+    fn test_poetry_titles_and_selah() {
+        let (text, content) = parse(r"\d A Psalm of David.\q1 The LORD is my shepherd;\q2 I shall not want.");
+        assert_eq!(text, "The LORD is my shepherd; I shall not want.");
+        assert_eq!(content.titles, vec![Title { kind: TitleKind::Superscription, text: "A Psalm of David.".to_string() }]);
+        assert_eq!(
+            content.lines,
+            vec![PoetryLine { start: 0, level: 1 }, PoetryLine { start: 25, level: 2 }]
+        );
+
+        let (text, content) = parse(r#"<title type="acrostic">ALEPH</title><l level="1">Blessed are the undefiled</l><l level="2">who walk</l>"#);
+        assert_eq!(text, "Blessed are the undefiled who walk");
+        assert_eq!(content.titles[0].kind, TitleKind::Acrostic);
+        assert_eq!(content.lines[1], PoetryLine { start: 26, level: 2 });
+
+        let verse = Verse::from_text(VerseReference::new("Ps", 3, 2), "no help for him in God. Selah.".to_string());
+        assert!(verse.content.style_at(24).selah);
+        assert!(!verse.content.style_at(20).selah);
+
+        let (text, content) = parse(r"\s1 The Beatitudes\p Blessed are the poor in spirit");
+        assert_eq!(text, "Blessed are the poor in spirit");
+        assert_eq!(content.titles, vec![Title { kind: TitleKind::Section, text: "The Beatitudes".to_string() }]);
+        assert!(content.paragraph);
+        let (_, content) = parse(r#"<title type="section">The Sermon on the Mount</title>And seeing the multitudes"#);
+        assert_eq!(content.titles[0].kind, TitleKind::Section);
+
+        assert_eq!(psalm_119_acrostic(9).as_deref(), Some("ב BETH"));
+        assert_eq!(psalm_119_acrostic(10), None);
+        assert_eq!(psalm_119_acrostic(176), None);
+    }
+
+    #[test]
+    fn test_paragraph_starts() {
+        let verse = |n: u32, text: &str| Verse::from_text(VerseReference::new("Gen", 1, n), text.to_string());
+        assert!(parse("¶ And God said").1.paragraph);
+        assert!(parse(r"\p In the beginning").1.paragraph);
+        assert!(!parse("And God ¶ said").1.paragraph);
+
+        // Marked paragraphs
+        let marked = vec![verse(1, "In the beginning."), verse(2, "And the earth."), verse(3, "¶ And God said.")];
+        assert_eq!(paragraph_starts(&marked), vec![true, false, true]);
+
+        // Unmarked: break after a sentence once a paragraph has four verses
+        let texts = ["A.", "B,", "C.", "D.", "E;", "F.", "G"];
+        let unmarked: Vec<Verse> = texts.iter().enumerate().map(|(i, t)| verse(i as u32 + 1, t)).collect();
+        assert_eq!(paragraph_starts(&unmarked), vec![true, false, false, false, true, false, false]);
+    }
+}
@@ -0,0 +1,204 @@
+use super::{book_id_to_name, book_name_to_id, Passage, VerseReference};
+use anyhow::{Context, Result};
+use rusqlite::{params, Connection};
+use std::io::BufRead;
+use std::path::Path;
+
+/// A cross-reference from one verse to a passage
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrossReference {
+    pub target: Passage,
+    /// Community votes for the link (OpenBible.info); higher is more relevant
+    pub votes: i64,
+}
+
+/// Cross-reference index stored in its own SQLite database
+///
+/// Cross-references don't depend on the translation, so they live beside
+/// the translation databases rather than inside any one of them.
+pub struct CrossRefIndex {
+    conn: Connection,
+}
+
+impl CrossRefIndex {
+    /// Open (creating if needed) the cross-reference database
+    pub fn open(db_path: impl AsRef<Path>) -> Result<Self> {
+        let conn = Connection::open(db_path)?;
+
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS cross_references (
+                from_book INTEGER NOT NULL,
+                from_chapter INTEGER NOT NULL,
+                from_verse INTEGER NOT NULL,
+                to_book INTEGER NOT NULL,
+                to_start_chapter INTEGER NOT NULL,
+                to_start_verse INTEGER NOT NULL,
+                to_end_chapter INTEGER NOT NULL,
+                to_end_verse INTEGER NOT NULL,
+                votes INTEGER NOT NULL DEFAULT 0
+            )",
+            [],
+        )?;
+
+        conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_cross_references_from
+             ON cross_references(from_book, from_chapter, from_verse)",
+            [],
+        )?;
+
+        Ok(Self { conn })
+    }
+
+    /// Cross-references for a verse, most votes first
+    pub fn lookup(&self, reference: &VerseReference) -> Result<Vec<CrossReference>> {
+        let Some(book_id) = book_name_to_id(&reference.book) else {
+            return Ok(Vec::new());
+        };
+
+        let mut stmt = self.conn.prepare(
+            "SELECT to_book, to_start_chapter, to_start_verse, to_end_chapter, to_end_verse, votes
+             FROM cross_references
+             WHERE from_book = ?1 AND from_chapter = ?2 AND from_verse = ?3
+             ORDER BY votes DESC, to_book, to_start_chapter, to_start_verse"
+        )?;
+
+        let rows = stmt.query_map(params![book_id, reference.chapter, reference.verse], |row| {
+            let book_id: u32 = row.get(0)?;
+            let start_chapter: u32 = row.get(1)?;
+            let start_verse: u32 = row.get(2)?;
+            let end_chapter: u32 = row.get(3)?;
+            let end_verse: u32 = row.get(4)?;
+            let votes: i64 = row.get(5)?;
+            Ok((book_id, start_chapter, start_verse, end_chapter, end_verse, votes))
+        })?;
+
+        let mut references = Vec::new();
+        for row in rows {
+            let (book_id, start_chapter, start_verse, end_chapter, end_verse, votes) = row?;
+            let Some(book) = book_id_to_name(book_id) else {
+                continue;
+            };
+            references.push(CrossReference {
+                target: Passage {
+                    book: book.to_string(),
+                    start_chapter,
+                    start_verse: Some(start_verse),
+                    end_chapter,
+                    end_verse: Some(end_verse),
+                },
+                votes,
+            });
+        }
+
+        Ok(references)
+    }
+
+    /// Replace the index with cross-references read from `input`
+    ///
+    /// Accepts the OpenBible.info format, which is also how the Treasury of
+    /// Scripture Knowledge is commonly distributed: tab-separated lines of
+    /// `From Verse`, `To Verse` and an optional `Votes`, using OSIS
+    /// references like `Gen.1.1` and `Prov.8.22-Prov.8.30`. A header line
+    /// and lines starting with `#` are skipped. Returns how many were imported.
+    pub fn import(&mut self, input: impl BufRead) -> Result<usize> {
+        let tx = self.conn.transaction()?;
+        tx.execute("DELETE FROM cross_references", [])?;
+
+        let mut count = 0;
+        {
+            let mut stmt = tx.prepare(
+                "INSERT INTO cross_references
+                 (from_book, from_chapter, from_verse, to_book, to_start_chapter,
+                  to_start_verse, to_end_chapter, to_end_verse, votes)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)"
+            )?;
+
+            for line in input.lines() {
+                let line = line.context("Failed to read cross-reference data")?;
+                let Some((from, to, votes)) = parse_line(&line) else {
+                    continue;
+                };
+                let (Some(from_book), Some(to_book)) =
+                    (book_name_to_id(&from.book), book_name_to_id(&to.book))
+                else {
+                    continue;
+                };
+
+                stmt.execute(params![
+                    from_book,
+                    from.chapter,
+                    from.verse,
+                    to_book,
+                    to.start_chapter,
+                    to.start_verse,
+                    to.end_chapter,
+                    to.end_verse,
+                    votes,
+                ])?;
+                count += 1;
+            }
+        }
+
+        tx.commit()?;
+        Ok(count)
+    }
+}
+
+/// Parse one data line into its source verse, target passage and votes
+fn parse_line(line: &str) -> Option<(VerseReference, Passage, i64)> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+
+    let mut fields = line.split('\t');
+    let from = parse_osis(fields.next()?.trim())?;
+    let to = fields.next()?.trim();
+    let votes = fields
+        .next()
+        .and_then(|votes| votes.trim().parse().ok())
+        .unwrap_or(0);
+
+    let (start, end) = match to.split_once('-') {
+        Some((start, end)) => (parse_osis(start)?, parse_osis(end)?),
+        None => {
+            let verse = parse_osis(to)?;
+            (verse.clone(), verse)
+        }
+    };
+
+    Some((from, Passage::between(&start, &end), votes))
+}
+
+/// Parse an OSIS verse reference like `Gen.1.1`
+fn parse_osis(reference: &str) -> Option<VerseReference> {
+    let mut parts = reference.split('.');
+    let book = parts.next()?;
+    let chapter = parts.next()?.parse().ok()?;
+    let verse = parts.next()?.parse().ok()?;
+    Some(VerseReference::new(book, chapter, verse))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_import_and_lookup() {
+        let data = "From Verse\tTo Verse\tVotes\t#www.openbible.info CC-BY 2024-01-01\n\
+                    Gen.1.1\tProv.8.22-Prov.8.30\t59\n\
+                    Gen.1.1\tJohn.1.1\t340\n\
+                    Gen.1.1\tHeb.11.3\n\
+                    Nope.1.1\tJohn.1.1\t5\n";
+
+        let mut index = CrossRefIndex::open(":memory:").unwrap();
+        assert_eq!(index.import(data.as_bytes()).unwrap(), 3);
+
+        let refs = index.lookup(&VerseReference::new("Gen", 1, 1)).unwrap();
+        let targets: Vec<String> = refs.iter().map(|r| r.target.to_string()).collect();
+        assert_eq!(targets, vec!["John 1:1", "Prov 8:22-30", "Heb 11:3"]);
+        assert_eq!(refs[2].votes, 0);
+
+        assert!(index.lookup(&VerseReference::new("Gen", 1, 2)).unwrap().is_empty());
+    }
+}
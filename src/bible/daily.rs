@@ -0,0 +1,180 @@
+use super::versification::VERSE_COUNTS;
+use super::loader::BibleLoader;
+use super::{Verse, VerseReference};
+use anyhow::Result;
+use chrono::{Datelike, NaiveDate};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+/// Where the verse of the day comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+pub enum DailyVerseSource {
+    /// Step through a curated list of well-known verses, one per day
+    Curated,
+    /// Pick any verse in the Bible, seeded by the date
+    Random,
+}
+
+/// Curated verses for the daily rotation
+pub const CURATED_VERSES: &[(&str, u32, u32)] = &[
+    ("Gen", 1, 1),
+    ("Josh", 1, 9),
+    ("Num", 6, 24),
+    ("Deut", 31, 6),
+    ("1Chr", 16, 34),
+    ("Neh", 8, 10),
+    ("Job", 19, 25),
+    ("Ps", 1, 1),
+    ("Ps", 19, 14),
+    ("Ps", 23, 1),
+    ("Ps", 27, 1),
+    ("Ps", 34, 8),
+    ("Ps", 37, 4),
+    ("Ps", 46, 1),
+    ("Ps", 46, 10),
+    ("Ps", 51, 10),
+    ("Ps", 90, 12),
+    ("Ps", 91, 1),
+    ("Ps", 103, 12),
+    ("Ps", 118, 24),
+    ("Ps", 119, 105),
+    ("Ps", 121, 1),
+    ("Ps", 139, 14),
+    ("Prov", 3, 5),
+    ("Prov", 16, 3),
+    ("Prov", 18, 10),
+    ("Eccl", 3, 1),
+    ("Isa", 26, 3),
+    ("Isa", 40, 31),
+    ("Isa", 41, 10),
+    ("Isa", 53, 5),
+    ("Jer", 29, 11),
+    ("Lam", 3, 22),
+    ("Mic", 6, 8),
+    ("Hab", 3, 18),
+    ("Zeph", 3, 17),
+    ("Matt", 5, 9),
+    ("Matt", 6, 33),
+    ("Matt", 11, 28),
+    ("Matt", 28, 20),
+    ("Mark", 10, 27),
+    ("Luke", 1, 37),
+    ("John", 1, 1),
+    ("John", 3, 16),
+    ("John", 8, 12),
+    ("John", 14, 6),
+    ("John", 14, 27),
+    ("John", 16, 33),
+    ("Acts", 1, 8),
+    ("Rom", 5, 8),
+    ("Rom", 8, 28),
+    ("Rom", 12, 2),
+    ("Rom", 15, 13),
+    ("1Cor", 13, 4),
+    ("2Cor", 5, 17),
+    ("2Cor", 12, 9),
+    ("Gal", 2, 20),
+    ("Gal", 5, 22),
+    ("Eph", 2, 8),
+    ("Eph", 4, 32),
+    ("Phil", 4, 6),
+    ("Phil", 4, 13),
+    ("Col", 3, 23),
+    ("1Thess", 5, 16),
+    ("2Tim", 1, 7),
+    ("Heb", 11, 1),
+    ("Heb", 13, 8),
+    ("Jas", 1, 5),
+    ("1Pet", 5, 7),
+    ("1John", 1, 9),
+    ("1John", 4, 19),
+    ("Rev", 21, 4),
+];
+
+/// Get the verse of the day for a date
+///
+/// The same date always gives the same verse, so every screen and shell
+/// prompt shows the same thing all day.
+pub fn verse_of_the_day(source: DailyVerseSource, date: NaiveDate) -> VerseReference {
+    match source {
+        DailyVerseSource::Curated => curated_for(date),
+        DailyVerseSource::Random => random_for(date),
+    }
+}
+
+/// The curated verse for a date, advancing one entry per day
+pub fn curated_for(date: NaiveDate) -> VerseReference {
+    let index = date.num_days_from_ce() as usize % CURATED_VERSES.len();
+    let (book, chapter, verse) = CURATED_VERSES[index];
+    VerseReference::new(book, chapter, verse)
+}
+
+/// A verse picked uniformly from the whole Bible, seeded by the date
+pub fn random_for(date: NaiveDate) -> VerseReference {
+    let total: u64 = VERSE_COUNTS
+        .iter()
+        .flat_map(|(_, counts)| counts.iter())
+        .map(|count| *count as u64)
+        .sum();
+    let mut remaining = splitmix64(date.num_days_from_ce() as u64) % total;
+
+    for (book, counts) in VERSE_COUNTS {
+        for (chapter_idx, count) in counts.iter().enumerate() {
+            let count = *count as u64;
+            if remaining < count {
+                return VerseReference::new(*book, chapter_idx as u32 + 1, remaining as u32 + 1);
+            }
+            remaining -= count;
+        }
+    }
+
+    curated_for(date)
+}
+
+/// Load the verse of the day, falling back to the curated list when the
+/// translation doesn't contain the picked verse
+pub fn load_verse_of_the_day(
+    loader: &BibleLoader,
+    source: DailyVerseSource,
+    date: NaiveDate,
+) -> Result<Option<Verse>> {
+    if let Some(verse) = loader.load_verse(&verse_of_the_day(source, date))? {
+        return Ok(Some(verse));
+    }
+
+    loader.load_verse(&curated_for(date))
+}
+
+/// SplitMix64 mixing step, used as a small stable hash of the date
+fn splitmix64(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bible::versification::is_valid_reference;
+
+    #[test]
+    fn test_curated_verses_are_valid() {
+        for (book, chapter, verse) in CURATED_VERSES {
+            let reference = VerseReference::new(*book, *chapter, *verse);
+            assert!(is_valid_reference(&reference), "{}", reference);
+        }
+    }
+
+    #[test]
+    fn test_verse_of_the_day_is_deterministic() {
+        let date = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+        let next = date.succ_opt().unwrap();
+
+        for source in [DailyVerseSource::Curated, DailyVerseSource::Random] {
+            assert_eq!(verse_of_the_day(source, date), verse_of_the_day(source, date));
+            assert!(is_valid_reference(&verse_of_the_day(source, date)));
+        }
+        assert_ne!(curated_for(date), curated_for(next));
+    }
+}
@@ -1,6 +1,9 @@
-use super::{Bible, Book, Chapter, Translation, Verse, VerseReference, BOOK_ORDER};
+use super::search::{match_offsets, SearchQuery};
+use super::content::TitleKind;
+use super::{Bible, Book, Chapter, Heading, Passage, Translation, Verse, VerseReference, BOOK_ORDER};
 use anyhow::{Context, Result};
 use rusqlite::{Connection, params};
+use std::collections::BTreeMap;
 use std::path::Path;
 
 /// Loads Bible data from SQLite database
@@ -75,10 +78,7 @@ impl BibleLoader {
             let verse_num: u32 = row.get(0)?;
             let text: String = row.get(1)?;
 
-            Ok(Verse {
-                reference: VerseReference::new(book, chapter, verse_num),
-                text,
-            })
+            Ok(Verse::from_text(VerseReference::new(book, chapter, verse_num), text))
         })?
         .collect::<Result<Vec<_>, _>>()?;
 
@@ -103,10 +103,7 @@ impl BibleLoader {
             params![book_id, reference.chapter, reference.verse],
             |row| {
                 let text: String = row.get(0)?;
-                Ok(Verse {
-                    reference: reference.clone(),
-                    text,
-                })
+                Ok(Verse::from_text(reference.clone(), text))
             }
         );
 
@@ -117,36 +114,202 @@ impl BibleLoader {
         }
     }
 
-    /// Search for verses containing a query string
-    pub fn search(&self, query: &str, limit: usize) -> Result<Vec<Verse>> {
-        let search_query = format!("%{}%", query);
+    /// Load every verse in a passage, in order
+    pub fn load_passage(&self, passage: &Passage) -> Result<Vec<Verse>> {
+        let book_id = super::book_name_to_id(&passage.book)
+            .ok_or_else(|| anyhow::anyhow!("Unknown book: {}", passage.book))?;
+
+        // Only the passage's verses, not the whole of each chapter it spans
         let mut stmt = self.conn.prepare(
             "SELECT book, chapter, verse, text FROM verses
-             WHERE text LIKE ?1
-             ORDER BY book, chapter, verse
-             LIMIT ?2"
+             WHERE book = ?1 AND (chapter, verse) BETWEEN (?2, ?3) AND (?4, ?5)
+             ORDER BY chapter, verse"
         )?;
 
-        let verses = stmt.query_map(params![search_query, limit as i64], |row| {
-            let book_id: u32 = row.get(0)?;
-            let chapter: u32 = row.get(1)?;
-            let verse: u32 = row.get(2)?;
-            let text: String = row.get(3)?;
+        let verses = stmt.query_map(
+            params![
+                book_id,
+                passage.start_chapter,
+                passage.start_verse.unwrap_or(1),
+                passage.end_chapter,
+                passage.end_verse.unwrap_or(u32::MAX),
+            ],
+            verse_from_row,
+        )?
+        .collect::<Result<Vec<_>, _>>()?;
 
-            // Convert book ID back to name
-            let book_name = super::book_id_to_name(book_id)
-                .unwrap_or("Unknown")
-                .to_string();
+        Ok(verses)
+    }
 
-            Ok(Verse {
-                reference: VerseReference::new(book_name, chapter, verse),
-                text,
-            })
-        })?
-        .collect::<Result<Vec<_>, _>>()?;
+    /// Load every verse of a book, or of the whole translation, in order
+    pub fn load_verses(&self, book: Option<&str>) -> Result<Vec<Verse>> {
+        let book_id = match book {
+            Some(book) => Some(
+                super::book_name_to_id(book)
+                    .ok_or_else(|| anyhow::anyhow!("Unknown book: {}", book))?,
+            ),
+            None => None,
+        };
+
+        let mut stmt = self.conn.prepare(
+            "SELECT book, chapter, verse, text FROM verses
+             WHERE ?1 IS NULL OR book = ?1
+             ORDER BY book, chapter, verse"
+        )?;
+
+        let verses = stmt.query_map(params![book_id], verse_from_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(verses)
+    }
+
+    /// Count the words tagged with a Strong's number across the translation
+    pub fn count_strongs(&self, number: &str) -> Result<usize> {
+        let Some(number) = super::strongs::normalize(number) else {
+            return Ok(0);
+        };
+
+        // Narrow by the digits in SQL, then count only the parsed tokens
+        // tagged with exactly this number: texts may write it zero-padded
+        // ("H0430"), and the digits also turn up inside longer numbers ("H4300")
+        let mut stmt = self.conn.prepare("SELECT text FROM verses WHERE text LIKE ?1")?;
+        let pattern = format!("%{}%", &number[1..]);
+
+        let mut count = 0;
+        let texts = stmt.query_map(params![pattern], |row| row.get::<_, String>(0))?;
+        for text in texts {
+            count += super::strongs::parse_tagged(&text?)
+                .iter()
+                .filter(|token| token.strongs.contains(&number))
+                .count();
+        }
+
+        Ok(count)
+    }
+
+    /// Section headings in the translation, in Bible order
+    pub fn load_headings(&self) -> Result<Vec<Heading>> {
+        // Only verses with title markup can start a section
+        let mut stmt = self.conn.prepare(
+            "SELECT book, chapter, verse, text FROM verses
+             WHERE text LIKE '%<title%' OR text LIKE '%\\s%' OR text LIKE '%\\ms%'
+             ORDER BY book, chapter, verse"
+        )?;
+
+        let mut headings = Vec::new();
+        for verse in stmt.query_map([], verse_from_row)? {
+            let verse = verse?;
+            for title in verse.content.titles {
+                if title.kind == TitleKind::Section {
+                    headings.push(Heading {
+                        reference: verse.reference.clone(),
+                        text: title.text,
+                    });
+                }
+            }
+        }
+
+        Ok(headings)
+    }
+
+    /// Search for verses matching a query, optionally within one book and
+    /// up to `limit` verses
+    pub fn search(&self, query: &SearchQuery, book: Option<&str>, limit: Option<usize>) -> Result<Vec<Verse>> {
+        let book_id = match book {
+            Some(book) => Some(
+                super::book_name_to_id(book)
+                    .ok_or_else(|| anyhow::anyhow!("Unknown book: {}", book))?,
+            ),
+            None => None,
+        };
+
+        match query {
+            SearchQuery::Text(text) => self.search_text(text, book_id, limit),
+            SearchQuery::Strongs(numbers) => self.search_strongs(numbers, book_id, limit),
+        }
+    }
+
+    /// Verses whose text contains a string
+    fn search_text(&self, query: &str, book_id: Option<u32>, limit: Option<usize>) -> Result<Vec<Verse>> {
+        // Markup and Strong's tags can sit between the words of a phrase, so
+        // narrow by its longest word in SQL, then match the parsed plain text
+        let narrow = query
+            .split(|c: char| !c.is_alphanumeric())
+            .max_by_key(|word| word.len())
+            .filter(|word| !word.is_empty())
+            .unwrap_or(query);
+        let search_query = format!("%{}%", narrow);
+
+        let mut stmt = self.conn.prepare(
+            "SELECT book, chapter, verse, text FROM verses
+             WHERE text LIKE ?1 AND (?2 IS NULL OR book = ?2)
+             ORDER BY book, chapter, verse"
+        )?;
+
+        let mut verses = Vec::new();
+        for verse in stmt.query_map(params![search_query, book_id], verse_from_row)? {
+            if limit.is_some_and(|limit| verses.len() >= limit) {
+                break;
+            }
+            let verse = verse?;
+            if !match_offsets(&verse.text, query).is_empty() {
+                verses.push(verse);
+            }
+        }
 
         Ok(verses)
     }
+
+    /// Verses with a word tagged with any of the Strong's numbers
+    fn search_strongs(&self, numbers: &[String], book_id: Option<u32>, limit: Option<usize>) -> Result<Vec<Verse>> {
+        // Narrow by each number's digits in SQL, then check the parsed tags
+        let mut stmt = self.conn.prepare(
+            "SELECT book, chapter, verse, text FROM verses
+             WHERE text LIKE ?1 AND (?2 IS NULL OR book = ?2)
+             ORDER BY book, chapter, verse"
+        )?;
+
+        let mut found = BTreeMap::new();
+        for number in numbers {
+            let pattern = format!("%{}%", &number[1..]);
+            let rows = stmt.query_map(params![pattern, book_id], |row| {
+                let book_id: u32 = row.get(0)?;
+                Ok(((book_id, row.get::<_, u32>(1)?, row.get::<_, u32>(2)?), verse_from_row(row)?))
+            })?;
+
+            for row in rows {
+                let (key, verse) = row?;
+                let tagged = verse
+                    .tokens
+                    .iter()
+                    .any(|token| token.strongs.iter().any(|n| numbers.contains(n)));
+                if tagged {
+                    found.insert(key, verse);
+                }
+            }
+        }
+
+        let verses = found.into_values();
+        Ok(match limit {
+            Some(limit) => verses.take(limit).collect(),
+            None => verses.collect(),
+        })
+    }
+}
+
+/// Build a verse from a `book, chapter, verse, text` row
+fn verse_from_row(row: &rusqlite::Row) -> rusqlite::Result<Verse> {
+    let book_id: u32 = row.get(0)?;
+    let chapter: u32 = row.get(1)?;
+    let verse: u32 = row.get(2)?;
+    let text: String = row.get(3)?;
+
+    // Convert book ID back to name
+    let book_name = super::book_id_to_name(book_id)
+        .unwrap_or("Unknown")
+        .to_string();
+
+    Ok(Verse::from_text(VerseReference::new(book_name, chapter, verse), text))
 }
 
 /// Initialize a new Bible database with the correct schema
@@ -190,3 +353,67 @@ pub fn init_database(db_path: impl AsRef<Path>) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_text_across_tags() {
+        let loader = BibleLoader::new(":memory:").unwrap();
+        loader
+            .conn
+            .execute_batch(
+                "CREATE TABLE verses (book INTEGER, chapter INTEGER, verse INTEGER, text TEXT);
+                 INSERT INTO verses VALUES
+                  (1, 1, 1, 'In the beginning<WH7225> God<WH430> created<WH1254> the heaven'),
+                  (1, 1, 2, 'And the earth<WH776> was without form, and God<WH430> moved');",
+            )
+            .unwrap();
+
+        let query = SearchQuery::Text("beginning God".to_string());
+        let verses = loader.search(&query, None, Some(10)).unwrap();
+        assert_eq!(verses.len(), 1);
+        assert_eq!(verses[0].reference, VerseReference::new("Gen", 1, 1));
+    }
+
+    #[test]
+    fn test_count_strongs_prefix() {
+        let loader = BibleLoader::new(":memory:").unwrap();
+        loader
+            .conn
+            .execute_batch(
+                "CREATE TABLE verses (book INTEGER, chapter INTEGER, verse INTEGER, text TEXT);
+                 INSERT INTO verses VALUES
+                  (1, 1, 1, 'God<H430> created'),
+                  (1, 1, 2, 'a word<H4300> and a word{H1430} and <w lemma=\"strong:H04300\">another</w>'),
+                  (1, 1, 3, 'the <w lemma=\"strong:H0430\">God</w> of gods<H430>');",
+            )
+            .unwrap();
+
+        assert_eq!(loader.count_strongs("H430").unwrap(), 3);
+        assert_eq!(loader.count_strongs("H4300").unwrap(), 2);
+    }
+
+    #[test]
+    fn test_load_passage() {
+        let loader = BibleLoader::new(":memory:").unwrap();
+        loader
+            .conn
+            .execute_batch(
+                "CREATE TABLE verses (book INTEGER, chapter INTEGER, verse INTEGER, text TEXT);
+                 INSERT INTO verses VALUES
+                  (43, 3, 15, 'a'), (43, 3, 16, 'b'), (43, 3, 17, 'c'),
+                  (43, 4, 1, 'd'), (43, 4, 2, 'e'), (44, 1, 1, 'f');",
+            )
+            .unwrap();
+
+        let texts = |reference: &str| -> Vec<String> {
+            let passage = crate::bible::parser::parse_passage(reference).unwrap();
+            loader.load_passage(&passage).unwrap().into_iter().map(|verse| verse.text).collect()
+        };
+        assert_eq!(texts("John 3:16"), vec!["b"]);
+        assert_eq!(texts("John 3:16-4:1"), vec!["b", "c", "d"]);
+        assert_eq!(texts("John 4"), vec!["d", "e"]);
+    }
+}
@@ -1,6 +1,14 @@
+pub mod analytics;
+pub mod content;
+pub mod crossrefs;
+pub mod daily;
 pub mod loader;
+pub mod modules;
 pub mod parser;
+pub mod plans;
 pub mod search;
+pub mod strongs;
+pub mod versification;
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -29,11 +37,151 @@ impl fmt::Display for VerseReference {
     }
 }
 
+/// Represents a span of verses within a single book
+///
+/// A missing start verse means the passage begins at the start of
+/// `start_chapter`; a missing end verse means it runs to the end of
+/// `end_chapter`. Serialized as its display string, e.g. "John 3:16-18".
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Passage {
+    pub book: String,
+    pub start_chapter: u32,
+    pub start_verse: Option<u32>,
+    pub end_chapter: u32,
+    pub end_verse: Option<u32>,
+}
+
+impl Passage {
+    /// The passage from one verse to another in the same book
+    pub fn between(start: &VerseReference, end: &VerseReference) -> Self {
+        Self {
+            book: start.book.clone(),
+            start_chapter: start.chapter,
+            start_verse: Some(start.verse),
+            end_chapter: end.chapter,
+            end_verse: Some(end.verse),
+        }
+    }
+
+    /// Check whether a verse falls inside this passage
+    pub fn contains(&self, reference: &VerseReference) -> bool {
+        if reference.book != self.book {
+            return false;
+        }
+
+        let start = (self.start_chapter, self.start_verse.unwrap_or(1));
+        let end = (self.end_chapter, self.end_verse.unwrap_or(u32::MAX));
+        let position = (reference.chapter, reference.verse);
+
+        start <= position && position <= end
+    }
+
+    /// The last verse of the passage, using versification for open-ended ranges
+    pub fn last_verse(&self) -> Option<VerseReference> {
+        let verse = match self.end_verse {
+            Some(verse) => verse,
+            None => versification::verse_count(&self.book, self.end_chapter)?,
+        };
+        Some(VerseReference::new(self.book.clone(), self.end_chapter, verse))
+    }
+}
+
+impl fmt::Display for Passage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.book, self.start_chapter)?;
+        if let Some(verse) = self.start_verse {
+            write!(f, ":{}", verse)?;
+        }
+
+        if self.end_chapter != self.start_chapter {
+            write!(f, "-{}", self.end_chapter)?;
+            if let Some(verse) = self.end_verse {
+                write!(f, ":{}", verse)?;
+            }
+        } else if let Some(verse) = self.end_verse.filter(|v| Some(*v) != self.start_verse) {
+            write!(f, "-{}", verse)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<String> for Passage {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        parser::parse_passage(&value).ok_or_else(|| format!("invalid passage: {}", value))
+    }
+}
+
+impl From<Passage> for String {
+    fn from(passage: Passage) -> Self {
+        passage.to_string()
+    }
+}
+
+impl From<&VerseReference> for Passage {
+    fn from(reference: &VerseReference) -> Self {
+        Self {
+            book: reference.book.clone(),
+            start_chapter: reference.chapter,
+            start_verse: Some(reference.verse),
+            end_chapter: reference.chapter,
+            end_verse: Some(reference.verse),
+        }
+    }
+}
+
 /// Represents a single verse with its content
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Verse {
     pub reference: VerseReference,
+    /// Plain text, with any Strong's tags and formatting markup removed
     pub text: String,
+    /// Words with their Strong's numbers, for tagged translations only
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tokens: Vec<strongs::Token>,
+    /// Red letters, italics, divine-name small caps and notes, where the
+    /// translation marks them
+    #[serde(default, skip_serializing_if = "content::Content::is_empty")]
+    pub content: content::Content,
+}
+
+impl Verse {
+    /// Build a verse from database text, splitting out formatting and
+    /// Strong's tags if present
+    pub fn from_text(reference: VerseReference, text: String) -> Self {
+        let (text, mut content) = content::parse(&text);
+
+        // Strong's tags and any markup left over
+        let (text, tokens) = if text.contains(['<', '{']) {
+            let tokens = strongs::parse_tagged(&text);
+            let tagged = tokens.iter().any(|token| !token.strongs.is_empty());
+            (strongs::plain_text(&tokens), if tagged { tokens } else { Vec::new() })
+        } else {
+            (text, Vec::new())
+        };
+
+        content::mark_selah(&text, &mut content);
+        if reference.book == "Ps" && reference.chapter == 119
+            && !content.titles.iter().any(|title| title.kind == content::TitleKind::Acrostic)
+        {
+            if let Some(text) = content::psalm_119_acrostic(reference.verse) {
+                content.titles.push(content::Title {
+                    kind: content::TitleKind::Acrostic,
+                    text,
+                });
+            }
+        }
+
+        Self {
+            reference,
+            text,
+            tokens,
+            content,
+        }
+    }
 }
 
 /// Represents a chapter containing multiple verses
@@ -44,6 +192,13 @@ pub struct Chapter {
     pub verses: Vec<Verse>,
 }
 
+/// A section heading and the verse it starts at
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Heading {
+    pub reference: VerseReference,
+    pub text: String,
+}
+
 /// Represents a book of the Bible
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Book {
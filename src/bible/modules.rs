@@ -0,0 +1,338 @@
+use super::parser::parse_passage;
+use super::{book_name_to_id, Passage, VerseReference};
+use anyhow::{bail, Context, Result};
+use clap::ValueEnum;
+use rusqlite::{params, Connection, OpenFlags};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// What a reference module's entries are keyed by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum ModuleKind {
+    /// Notes keyed by verse or passage, e.g. Matthew Henry, JFB
+    Commentary,
+    /// Articles keyed by topic, e.g. Easton's, Smith's
+    Dictionary,
+    /// Topics listing the verses about them, e.g. Nave's
+    Topical,
+}
+
+impl ModuleKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            ModuleKind::Commentary => "commentary",
+            ModuleKind::Dictionary => "dictionary",
+            ModuleKind::Topical => "topical",
+        }
+    }
+
+    fn parse(kind: &str) -> Option<Self> {
+        match kind {
+            "commentary" => Some(ModuleKind::Commentary),
+            "dictionary" => Some(ModuleKind::Dictionary),
+            "topical" => Some(ModuleKind::Topical),
+            _ => None,
+        }
+    }
+}
+
+/// An installed reference module
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleInfo {
+    /// Short identifier, e.g. "mhc"
+    pub id: String,
+    pub name: String,
+    pub kind: ModuleKind,
+}
+
+/// An entry of a reference module: a commentary note, dictionary article or topic
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleEntry {
+    /// Passage for commentaries, headword or topic otherwise
+    pub key: String,
+    pub text: String,
+}
+
+/// A module read from an import file, before it is stored
+#[derive(Debug, Deserialize)]
+pub struct ModuleFile {
+    #[serde(default)]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub kind: Option<ModuleKind>,
+    pub entries: Vec<ModuleFileEntry>,
+}
+
+/// An entry in an import file
+#[derive(Debug, Deserialize)]
+pub struct ModuleFileEntry {
+    pub key: String,
+    #[serde(default)]
+    pub text: String,
+    /// Passages the entry is about; commentaries default to their key
+    #[serde(default)]
+    pub references: Vec<String>,
+}
+
+impl ModuleFile {
+    /// Read a module from JSON:
+    /// `{"name": "...", "kind": "commentary", "entries": [{"key": "Gen 1:1-2", "text": "..."}]}`.
+    /// Dictionary and topical entries list the verses they cover in `references`.
+    pub fn from_json(input: &str) -> Result<Self> {
+        serde_json::from_str(input).context("Failed to parse module JSON")
+    }
+
+    /// Read a module from a SQLite database with an `entries` table of `key`
+    /// and `text` columns, and optionally `references` separated by `;`
+    pub fn from_sqlite(path: impl AsRef<Path>) -> Result<Self> {
+        let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+            .context("Failed to open module database")?;
+
+        let mut stmt = conn
+            .prepare("SELECT * FROM entries")
+            .context("Module database has no entries table")?;
+        let columns: Vec<String> = stmt.column_names().iter().map(|name| name.to_lowercase()).collect();
+        let column = |name: &str| columns.iter().position(|column| column == name);
+        let (Some(key), Some(text)) = (column("key"), column("text")) else {
+            bail!("Module entries table needs key and text columns");
+        };
+        let references = column("references");
+
+        let entries = stmt
+            .query_map([], |row| {
+                let references: Option<String> = match references {
+                    Some(idx) => row.get(idx)?,
+                    None => None,
+                };
+                Ok(ModuleFileEntry {
+                    key: row.get(key)?,
+                    text: row.get::<_, Option<String>>(text)?.unwrap_or_default(),
+                    references: references
+                        .unwrap_or_default()
+                        .split(';')
+                        .map(str::trim)
+                        .filter(|reference| !reference.is_empty())
+                        .map(str::to_string)
+                        .collect(),
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            id: None,
+            name: None,
+            kind: None,
+            entries,
+        })
+    }
+}
+
+/// Commentaries, dictionaries and topical indexes stored in their own
+/// SQLite database, shared by all translations
+pub struct ModuleStore {
+    conn: Connection,
+}
+
+impl ModuleStore {
+    /// Open (creating if needed) the module database
+    pub fn open(db_path: impl AsRef<Path>) -> Result<Self> {
+        let conn = Connection::open(db_path)?;
+
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS modules (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL,
+                kind TEXT NOT NULL
+            );
+            CREATE TABLE IF NOT EXISTS module_entries (
+                id INTEGER PRIMARY KEY,
+                module TEXT NOT NULL,
+                key TEXT NOT NULL,
+                text TEXT NOT NULL
+            );
+            CREATE TABLE IF NOT EXISTS module_references (
+                entry INTEGER NOT NULL,
+                book INTEGER NOT NULL,
+                start_chapter INTEGER NOT NULL,
+                start_verse INTEGER NOT NULL,
+                end_chapter INTEGER NOT NULL,
+                end_verse INTEGER NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS idx_module_entries_module
+             ON module_entries(module);
+            CREATE INDEX IF NOT EXISTS idx_module_references_book
+             ON module_references(book, start_chapter);",
+        )?;
+
+        Ok(Self { conn })
+    }
+
+    /// Installed modules, commentaries first
+    pub fn modules(&self) -> Result<Vec<ModuleInfo>> {
+        let mut stmt = self.conn.prepare("SELECT id, name, kind FROM modules ORDER BY name")?;
+        let rows = stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?, row.get::<_, String>(2)?))
+        })?;
+
+        let mut modules = Vec::new();
+        for row in rows {
+            let (id, name, kind) = row?;
+            if let Some(kind) = ModuleKind::parse(&kind) {
+                modules.push(ModuleInfo { id, name, kind });
+            }
+        }
+        modules.sort_by_key(|module| module.kind != ModuleKind::Commentary);
+        Ok(modules)
+    }
+
+    /// Entries of a module about a verse, the most specific passage first
+    pub fn entries_for(&self, module: &str, reference: &VerseReference) -> Result<Vec<ModuleEntry>> {
+        let Some(book_id) = book_name_to_id(&reference.book) else {
+            return Ok(Vec::new());
+        };
+
+        let mut stmt = self.conn.prepare(
+            "SELECT DISTINCT e.id, e.key, e.text, r.start_chapter, r.start_verse
+             FROM module_entries e JOIN module_references r ON r.entry = e.id
+             WHERE e.module = ?1 AND r.book = ?2
+               AND (r.start_chapter < ?3 OR (r.start_chapter = ?3 AND r.start_verse <= ?4))
+               AND (r.end_chapter > ?3 OR (r.end_chapter = ?3 AND r.end_verse >= ?4))
+             ORDER BY r.start_chapter DESC, r.start_verse DESC, r.end_chapter, r.end_verse, e.id"
+        )?;
+
+        let rows = stmt.query_map(params![module, book_id, reference.chapter, reference.verse], |row| {
+            Ok((row.get::<_, i64>(0)?, ModuleEntry { key: row.get(1)?, text: row.get(2)? }))
+        })?;
+
+        // An entry citing several covering passages is listed once
+        let mut seen = Vec::new();
+        let mut entries = Vec::new();
+        for row in rows {
+            let (id, entry) = row?;
+            if !seen.contains(&id) {
+                seen.push(id);
+                entries.push(entry);
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Store a module, replacing any module with the same id. Entries whose
+    /// references can't be parsed are kept, just not linked to verses.
+    /// Returns how many entries were imported.
+    pub fn import(&mut self, info: &ModuleInfo, file: &ModuleFile) -> Result<usize> {
+        let tx = self.conn.transaction()?;
+        tx.execute(
+            "DELETE FROM module_references WHERE entry IN
+             (SELECT id FROM module_entries WHERE module = ?1)",
+            params![info.id],
+        )?;
+        tx.execute("DELETE FROM module_entries WHERE module = ?1", params![info.id])?;
+        tx.execute(
+            "INSERT OR REPLACE INTO modules (id, name, kind) VALUES (?1, ?2, ?3)",
+            params![info.id, info.name, info.kind.as_str()],
+        )?;
+
+        let mut count = 0;
+        {
+            let mut insert_entry = tx.prepare(
+                "INSERT INTO module_entries (module, key, text) VALUES (?1, ?2, ?3)"
+            )?;
+            let mut insert_reference = tx.prepare(
+                "INSERT INTO module_references
+                 (entry, book, start_chapter, start_verse, end_chapter, end_verse)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)"
+            )?;
+
+            for entry in &file.entries {
+                insert_entry.execute(params![info.id, entry.key.trim(), entry.text.trim()])?;
+                let entry_id = tx.last_insert_rowid();
+                count += 1;
+
+                for passage in entry_passages(info.kind, entry) {
+                    let Some(book_id) = book_name_to_id(&passage.book) else {
+                        continue;
+                    };
+                    insert_reference.execute(params![
+                        entry_id,
+                        book_id,
+                        passage.start_chapter,
+                        passage.start_verse.unwrap_or(1),
+                        passage.end_chapter,
+                        passage.end_verse.unwrap_or(u32::MAX),
+                    ])?;
+                }
+            }
+        }
+
+        tx.commit()?;
+        Ok(count)
+    }
+}
+
+/// Passages an entry is about: its references, or its key for a commentary
+/// entry without any
+fn entry_passages(kind: ModuleKind, entry: &ModuleFileEntry) -> Vec<Passage> {
+    if entry.references.is_empty() && kind == ModuleKind::Commentary {
+        return parse_passage(&entry.key).into_iter().collect();
+    }
+    entry
+        .references
+        .iter()
+        .filter_map(|reference| parse_passage(reference))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_import_and_lookup() {
+        let file = ModuleFile::from_json(
+            r#"{"name": "Concise", "kind": "commentary", "entries": [
+                {"key": "Gen 1:1-2", "text": "The creation."},
+                {"key": "Gen 1:2", "text": "The Spirit."},
+                {"key": "Gen 1", "text": "The first chapter."}
+            ]}"#,
+        )
+        .unwrap();
+        let info = ModuleInfo { id: "mhc".to_string(), name: "Concise".to_string(), kind: file.kind.unwrap() };
+
+        let mut store = ModuleStore::open(":memory:").unwrap();
+        assert_eq!(store.import(&info, &file).unwrap(), 3);
+        // Re-importing replaces the module
+        assert_eq!(store.import(&info, &file).unwrap(), 3);
+        assert_eq!(store.modules().unwrap(), vec![info]);
+
+        let keys = |verse| -> Vec<String> {
+            store
+                .entries_for("mhc", &VerseReference::new("Gen", 1, verse))
+                .unwrap()
+                .into_iter()
+                .map(|entry| entry.key)
+                .collect()
+        };
+        assert_eq!(keys(2), vec!["Gen 1:2", "Gen 1:1-2", "Gen 1"]);
+        assert_eq!(keys(3), vec!["Gen 1"]);
+    }
+
+    #[test]
+    fn test_topical_references() {
+        let file = ModuleFile::from_json(
+            r#"{"entries": [{"key": "Faith", "references": ["Heb 11:1", "Heb 11:6", "Rom 10:17"]}]}"#,
+        )
+        .unwrap();
+        let info = ModuleInfo { id: "naves".to_string(), name: "Nave's".to_string(), kind: ModuleKind::Topical };
+
+        let mut store = ModuleStore::open(":memory:").unwrap();
+        store.import(&info, &file).unwrap();
+
+        let entries = store.entries_for("naves", &VerseReference::new("Heb", 11, 6)).unwrap();
+        assert_eq!(entries[0].key, "Faith");
+        assert!(store.entries_for("naves", &VerseReference::new("Heb", 11, 2)).unwrap().is_empty());
+    }
+}
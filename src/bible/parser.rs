@@ -1,3 +1,4 @@
+use super::{Passage, BOOK_ORDER};
 use anyhow::{Context, Result};
 use rusqlite::Connection;
 use serde_json::Value;
@@ -85,6 +86,238 @@ pub fn parse_reference(input: &str) -> Option<(String, u32, u32)> {
     Some((book, chapter, verse))
 }
 
+/// Common abbreviations and alternate names, mapped to canonical short names
+///
+/// Short and full names from `BOOK_ORDER` are always accepted, so only extra
+/// spellings belong here. Keys are compared after `normalize_book_name`.
+pub const BOOK_ALIASES: &[(&str, &str)] = &[
+    ("Gn", "Gen"), ("Ge", "Gen"),
+    ("Ex", "Exod"), ("Exo", "Exod"),
+    ("Lv", "Lev"), ("Le", "Lev"),
+    ("Nm", "Num"), ("Nu", "Num"),
+    ("Dt", "Deut"), ("De", "Deut"),
+    ("Jos", "Josh"),
+    ("Jdg", "Judg"), ("Jg", "Judg"),
+    ("Rt", "Ruth"), ("Ru", "Ruth"),
+    ("1Sa", "1Sam"), ("2Sa", "2Sam"),
+    ("1Ki", "1Kgs"), ("2Ki", "2Kgs"),
+    ("1Ch", "1Chr"), ("2Ch", "2Chr"),
+    ("Ne", "Neh"),
+    ("Est", "Esth"),
+    ("Psalm", "Ps"), ("Pss", "Ps"), ("Psa", "Ps"),
+    ("Pr", "Prov"), ("Prv", "Prov"),
+    ("Ecc", "Eccl"), ("Qoh", "Eccl"),
+    ("Song of Songs", "Song"), ("SS", "Song"), ("Canticles", "Song"),
+    ("Is", "Isa"),
+    ("Jr", "Jer"),
+    ("Ez", "Ezek"), ("Eze", "Ezek"),
+    ("Dn", "Dan"),
+    ("Ho", "Hos"),
+    ("Jl", "Joel"),
+    ("Am", "Amos"),
+    ("Ob", "Obad"),
+    ("Jon", "Jonah"),
+    ("Mi", "Mic"),
+    ("Na", "Nah"),
+    ("Hb", "Hab"),
+    ("Zep", "Zeph"),
+    ("Hg", "Hag"),
+    ("Zc", "Zech"), ("Zec", "Zech"),
+    ("Ml", "Mal"),
+    ("Mt", "Matt"),
+    ("Mk", "Mark"), ("Mr", "Mark"),
+    ("Lk", "Luke"), ("Lu", "Luke"),
+    ("Jn", "John"), ("Joh", "John"),
+    ("Ac", "Acts"),
+    ("Ro", "Rom"), ("Rm", "Rom"),
+    ("1Co", "1Cor"), ("2Co", "2Cor"),
+    ("Ga", "Gal"),
+    ("Ep", "Eph"),
+    ("Php", "Phil"), ("Pp", "Phil"),
+    ("1Th", "1Thess"), ("2Th", "2Thess"),
+    ("1Ti", "1Tim"), ("2Ti", "2Tim"),
+    ("Tit", "Titus"),
+    ("Phm", "Phlm"), ("Philem", "Phlm"),
+    ("He", "Heb"),
+    ("Jm", "Jas"), ("Jam", "Jas"),
+    ("1Pe", "1Pet"), ("2Pe", "2Pet"),
+    ("1Jn", "1John"), ("2Jn", "2John"), ("3Jn", "3John"),
+    ("Jud", "Jude"),
+    ("Re", "Rev"), ("Revelations", "Rev"),
+];
+
+/// Normalize a book name for comparison: lowercase, no spaces or periods
+fn normalize_book_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| !c.is_whitespace() && *c != '.')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Look up a book by its exact short name, full name or alias
+pub fn lookup_book(input: &str) -> Option<&'static str> {
+    let needle = normalize_book_name(input);
+    if needle.is_empty() {
+        return None;
+    }
+
+    BOOK_ORDER
+        .iter()
+        .find(|(short, full, _, _)| {
+            normalize_book_name(short) == needle || normalize_book_name(full) == needle
+        })
+        .map(|(short, _, _, _)| *short)
+        .or_else(|| {
+            BOOK_ALIASES
+                .iter()
+                .find(|(alias, _)| normalize_book_name(alias) == needle)
+                .map(|(_, short)| *short)
+        })
+}
+
+/// Resolve a book name or abbreviation to its canonical short name
+///
+/// Exact names and aliases win; otherwise the input must be an unambiguous
+/// prefix of a single book.
+pub fn resolve_book(input: &str) -> Option<&'static str> {
+    if let Some(short) = lookup_book(input) {
+        return Some(short);
+    }
+
+    let needle = normalize_book_name(input);
+    if needle.is_empty() {
+        return None;
+    }
+
+    let mut matches = BOOK_ORDER.iter().filter(|(short, full, _, _)| {
+        normalize_book_name(short).starts_with(&needle)
+            || normalize_book_name(full).starts_with(&needle)
+    });
+
+    match (matches.next(), matches.next()) {
+        (Some((short, _, _, _)), None) => Some(short),
+        _ => None,
+    }
+}
+
+/// Parse a passage like "John 3:16-18", "Ps 23", "Rom 8-9" or "Gen 1:26-2:3"
+pub fn parse_passage(input: &str) -> Option<Passage> {
+    let input = input.trim();
+
+    // The chapter/verse part is everything after the last space
+    let (book_part, range_part) = input.rsplit_once(char::is_whitespace)?;
+    let book = resolve_book(book_part)?;
+
+    let parse_point = |s: &str| -> Option<(u32, Option<u32>)> {
+        match s.split_once(':') {
+            Some((chapter, verse)) => Some((chapter.parse().ok()?, Some(verse.parse().ok()?))),
+            None => Some((s.parse().ok()?, None)),
+        }
+    };
+
+    let (start, end) = match range_part.split_once('-') {
+        Some((start, end)) => (start, Some(end)),
+        None => (range_part, None),
+    };
+
+    let (start_chapter, start_verse) = parse_point(start)?;
+    let (end_chapter, end_verse) = match (end, start_verse) {
+        // "3:16-18" continues in the same chapter
+        (Some(end), Some(_)) if !end.contains(':') => (start_chapter, Some(end.parse().ok()?)),
+        (Some(end), _) => parse_point(end)?,
+        (None, verse) => (start_chapter, verse),
+    };
+
+    if start_chapter == 0 || (end_chapter, end_verse) < (start_chapter, start_verse) {
+        return None;
+    }
+
+    Some(Passage {
+        book: book.to_string(),
+        start_chapter,
+        start_verse,
+        end_chapter,
+        end_verse,
+    })
+}
+
+/// Find scripture references in free text, such as a Markdown lesson
+///
+/// Returns the byte range of each reference along with the parsed passage.
+/// Only exact book names and aliases starting with a capital letter or digit
+/// are recognized, and a chapter:verse is required, so ordinary prose like
+/// "Mark 5 people" is left alone.
+pub fn find_passages(text: &str) -> Vec<(std::ops::Range<usize>, Passage)> {
+    // Word tokens with their byte ranges
+    let words: Vec<(usize, &str)> = text
+        .split_whitespace()
+        .map(|word| (word.as_ptr() as usize - text.as_ptr() as usize, word))
+        .collect();
+
+    let mut found = Vec::new();
+    let mut i = 0;
+
+    while i < words.len() {
+        match match_passage_at(&words, i) {
+            Some((range, passage, next)) => {
+                found.push((range, passage));
+                i = next;
+            }
+            None => i += 1,
+        }
+    }
+
+    found
+}
+
+/// Try to match a reference whose book name starts at word `start`
+fn match_passage_at(
+    words: &[(usize, &str)],
+    start: usize,
+) -> Option<(std::ops::Range<usize>, Passage, usize)> {
+    const MAX_BOOK_WORDS: usize = 3;
+    const OPENING: &[char] = &['(', '[', '"', '\'', '*', '_'];
+    const CLOSING: &[char] = &[')', ']', '"', '\'', '*', '_', ',', '.', ';', ':', '!', '?'];
+
+    let (first_offset, first_word) = words[start];
+    let book_start = first_offset + (first_word.len() - first_word.trim_start_matches(OPENING).len());
+
+    for book_words in (1..=MAX_BOOK_WORDS).rev() {
+        let range_idx = start + book_words;
+        // Near the end of the text there may be no room for a longer book name
+        let Some(&(range_offset, range_word)) = words.get(range_idx) else {
+            continue;
+        };
+
+        let book_text: Vec<&str> = words[start..range_idx].iter().map(|(_, w)| *w).collect();
+        let book_text = book_text.join(" ");
+        let book_text = book_text.trim_start_matches(OPENING);
+        if !book_text.starts_with(|c: char| c.is_uppercase() || c.is_ascii_digit()) {
+            continue;
+        }
+        let Some(book) = lookup_book(book_text) else {
+            continue;
+        };
+
+        let range_text = range_word.trim_end_matches(CLOSING);
+        if !range_text.contains(':') {
+            continue;
+        }
+
+        let Some(passage) = parse_passage(&format!("{} {}", book, range_text)) else {
+            continue;
+        };
+        if super::versification::verse_count(&passage.book, passage.end_chapter).is_none() {
+            continue;
+        }
+
+        let end = range_offset + range_text.len();
+        return Some((book_start..end, passage, range_idx + 1));
+    }
+
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -106,4 +339,61 @@ mod tests {
             Some(("Genesis".to_string(), 1, 1))
         );
     }
+
+    #[test]
+    fn test_resolve_book() {
+        assert_eq!(resolve_book("John"), Some("John"));
+        assert_eq!(resolve_book("1 John"), Some("1John"));
+        assert_eq!(resolve_book("psalm"), Some("Ps"));
+        assert_eq!(resolve_book("Rom."), Some("Rom"));
+        assert_eq!(resolve_book("Song of Solomon"), Some("Song"));
+        assert_eq!(resolve_book("Jo"), None);
+    }
+
+    #[test]
+    fn test_parse_passage() {
+        let passage = parse_passage("John 3:16-18").unwrap();
+        assert_eq!(passage.book, "John");
+        assert_eq!((passage.start_chapter, passage.start_verse), (3, Some(16)));
+        assert_eq!((passage.end_chapter, passage.end_verse), (3, Some(18)));
+        assert_eq!(passage.to_string(), "John 3:16-18");
+
+        let passage = parse_passage("Ps 23").unwrap();
+        assert_eq!((passage.start_chapter, passage.start_verse), (23, None));
+        assert_eq!((passage.end_chapter, passage.end_verse), (23, None));
+
+        let passage = parse_passage("1 John 1-2").unwrap();
+        assert_eq!(passage.book, "1John");
+        assert_eq!(passage.end_chapter, 2);
+
+        let passage = parse_passage("Gen 1:26-2:3").unwrap();
+        assert_eq!((passage.end_chapter, passage.end_verse), (2, Some(3)));
+        assert_eq!(passage.to_string(), "Gen 1:26-2:3");
+
+        assert_eq!(parse_passage("John"), None);
+        assert_eq!(parse_passage("John 3:18-16"), None);
+        assert_eq!(parse_passage("Nowhere 1:1"), None);
+    }
+
+    #[test]
+    fn test_find_passages() {
+        let text = "Read Jn 3:16-18 and (1 Cor. 13:4). Mark 5 people, not john 1:1.";
+        let found = find_passages(text);
+
+        assert_eq!(found.len(), 2);
+        assert_eq!(&text[found[0].0.clone()], "Jn 3:16-18");
+        assert_eq!(found[0].1.to_string(), "John 3:16-18");
+        assert_eq!(&text[found[1].0.clone()], "1 Cor. 13:4");
+        assert_eq!(found[1].1.to_string(), "1Cor 13:4");
+    }
+
+    #[test]
+    fn test_find_trailing_passage() {
+        let text = "See Jn 3:16.";
+        let found = find_passages(text);
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(&text[found[0].0.clone()], "Jn 3:16");
+        assert_eq!(found[0].1.to_string(), "John 3:16");
+    }
 }
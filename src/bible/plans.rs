@@ -0,0 +1,313 @@
+use super::{Passage, Testament, BOOK_ORDER};
+use serde::{Deserialize, Serialize};
+
+/// A reading plan: a list of days, each with one or more passages to read
+///
+/// User-defined plans use the same shape in TOML or JSON:
+///
+/// ```toml
+/// name = "Gospels in a month"
+/// description = "One Gospel a week"
+/// days = [["Matt 1-3"], ["Matt 4-6", "Ps 1"]]
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadingPlan {
+    /// Stable identifier (file stem for user plans)
+    #[serde(skip)]
+    pub id: String,
+
+    /// Display name
+    pub name: String,
+
+    /// Short description shown in the plan picker
+    #[serde(default)]
+    pub description: String,
+
+    /// Passages assigned to each day, starting with day 1
+    pub days: Vec<Vec<Passage>>,
+}
+
+impl ReadingPlan {
+    /// Number of days in the plan
+    pub fn len(&self) -> usize {
+        self.days.len()
+    }
+
+    /// Total number of readings across all days
+    pub fn reading_count(&self) -> usize {
+        self.days.iter().map(Vec::len).sum()
+    }
+}
+
+/// Chronological order as (book, first chapter, last chapter) segments
+///
+/// Follows the usual approximate ordering: Job alongside the patriarchs,
+/// Psalms and wisdom books with David and Solomon, prophets with the kings
+/// they addressed, and epistles interleaved with Acts.
+const CHRONOLOGICAL_ORDER: &[(&str, u32, u32)] = &[
+    ("Gen", 1, 11),
+    ("Job", 1, 42),
+    ("Gen", 12, 50),
+    ("Exod", 1, 40),
+    ("Lev", 1, 27),
+    ("Num", 1, 36),
+    ("Deut", 1, 34),
+    ("Josh", 1, 24),
+    ("Judg", 1, 21),
+    ("Ruth", 1, 4),
+    ("1Sam", 1, 31),
+    ("2Sam", 1, 24),
+    ("1Chr", 1, 29),
+    ("Ps", 1, 72),
+    ("1Kgs", 1, 11),
+    ("Prov", 1, 31),
+    ("Eccl", 1, 12),
+    ("Song", 1, 8),
+    ("Ps", 73, 89),
+    ("1Kgs", 12, 22),
+    ("2Kgs", 1, 14),
+    ("Jonah", 1, 4),
+    ("Amos", 1, 9),
+    ("Hos", 1, 14),
+    ("Joel", 1, 3),
+    ("2Kgs", 15, 17),
+    ("Isa", 1, 39),
+    ("Mic", 1, 7),
+    ("2Kgs", 18, 20),
+    ("Isa", 40, 66),
+    ("2Kgs", 21, 23),
+    ("Nah", 1, 3),
+    ("Zeph", 1, 3),
+    ("Jer", 1, 52),
+    ("Hab", 1, 3),
+    ("2Kgs", 24, 25),
+    ("Lam", 1, 5),
+    ("2Chr", 1, 36),
+    ("Obad", 1, 1),
+    ("Ezek", 1, 48),
+    ("Dan", 1, 12),
+    ("Ps", 90, 150),
+    ("Ezra", 1, 6),
+    ("Hag", 1, 2),
+    ("Zech", 1, 14),
+    ("Esth", 1, 10),
+    ("Ezra", 7, 10),
+    ("Neh", 1, 13),
+    ("Mal", 1, 4),
+    ("Mark", 1, 16),
+    ("Matt", 1, 28),
+    ("Luke", 1, 24),
+    ("John", 1, 21),
+    ("Acts", 1, 14),
+    ("Jas", 1, 5),
+    ("Gal", 1, 6),
+    ("Acts", 15, 18),
+    ("1Thess", 1, 5),
+    ("2Thess", 1, 3),
+    ("Acts", 19, 20),
+    ("1Cor", 1, 16),
+    ("2Cor", 1, 13),
+    ("Rom", 1, 16),
+    ("Acts", 21, 28),
+    ("Eph", 1, 6),
+    ("Phil", 1, 4),
+    ("Col", 1, 4),
+    ("Phlm", 1, 1),
+    ("1Tim", 1, 6),
+    ("Titus", 1, 3),
+    ("1Pet", 1, 5),
+    ("Heb", 1, 13),
+    ("2Tim", 1, 4),
+    ("2Pet", 1, 3),
+    ("Jude", 1, 1),
+    ("1John", 1, 5),
+    ("2John", 1, 1),
+    ("3John", 1, 1),
+    ("Rev", 1, 22),
+];
+
+/// All built-in reading plans
+pub fn builtin_plans() -> Vec<ReadingPlan> {
+    vec![
+        mcheyne(),
+        chronological(),
+        canonical_one_year(),
+        new_testament_90_days(),
+    ]
+}
+
+/// M'Cheyne-style plan: four daily streams over a year
+///
+/// Streams start at Genesis, Matthew, Ezra and Acts, covering the Old
+/// Testament once and the New Testament twice.
+fn mcheyne() -> ReadingPlan {
+    let streams = [
+        chapters_between("Gen", "2Chr"),
+        chapters_between("Matt", "Rev"),
+        chapters_between("Ezra", "Mal"),
+        [chapters_between("Acts", "Rev"), chapters_between("Matt", "John")].concat(),
+    ];
+
+    let stream_days: Vec<Vec<Vec<Passage>>> = streams
+        .iter()
+        .map(|chapters| split_into_days(chapters, 365))
+        .collect();
+
+    let days = (0..365)
+        .map(|day| stream_days.iter().flat_map(|stream| stream[day].clone()).collect())
+        .collect();
+
+    ReadingPlan {
+        id: "mcheyne".to_string(),
+        name: "M'Cheyne".to_string(),
+        description: "Four readings a day; OT once and NT twice in a year".to_string(),
+        days,
+    }
+}
+
+/// The whole Bible in the order events happened, over a year
+fn chronological() -> ReadingPlan {
+    let chapters: Vec<(&'static str, u32)> = CHRONOLOGICAL_ORDER
+        .iter()
+        .flat_map(|(book, first, last)| (*first..=*last).map(move |chapter| (*book, chapter)))
+        .collect();
+
+    ReadingPlan {
+        id: "chronological".to_string(),
+        name: "Chronological".to_string(),
+        description: "The whole Bible in historical order in a year".to_string(),
+        days: split_into_days(&chapters, 365),
+    }
+}
+
+/// The whole Bible in canonical order, over a year
+fn canonical_one_year() -> ReadingPlan {
+    ReadingPlan {
+        id: "canonical".to_string(),
+        name: "Bible in a Year".to_string(),
+        description: "Genesis to Revelation in a year".to_string(),
+        days: split_into_days(&chapters_between("Gen", "Rev"), 365),
+    }
+}
+
+/// The New Testament over 90 days
+fn new_testament_90_days() -> ReadingPlan {
+    let chapters: Vec<(&'static str, u32)> = BOOK_ORDER
+        .iter()
+        .filter(|(_, _, testament, _)| *testament == Testament::New)
+        .flat_map(|(book, _, _, count)| (1..=*count).map(move |chapter| (*book, chapter)))
+        .collect();
+
+    ReadingPlan {
+        id: "nt90".to_string(),
+        name: "New Testament in 90 Days".to_string(),
+        description: "Matthew to Revelation in about three chapters a day".to_string(),
+        days: split_into_days(&chapters, 90),
+    }
+}
+
+/// Every chapter from the first book to the last book, inclusive, in canonical order
+fn chapters_between(first: &str, last: &str) -> Vec<(&'static str, u32)> {
+    let start = BOOK_ORDER.iter().position(|(book, _, _, _)| *book == first).unwrap_or(0);
+    let end = BOOK_ORDER
+        .iter()
+        .position(|(book, _, _, _)| *book == last)
+        .unwrap_or(BOOK_ORDER.len() - 1);
+
+    BOOK_ORDER[start..=end]
+        .iter()
+        .flat_map(|(book, _, _, count)| (1..=*count).map(move |chapter| (*book, chapter)))
+        .collect()
+}
+
+/// Spread chapters as evenly as possible over `days`, merging runs within a book
+fn split_into_days(chapters: &[(&'static str, u32)], days: usize) -> Vec<Vec<Passage>> {
+    (0..days)
+        .map(|day| {
+            let start = day * chapters.len() / days;
+            let end = (day + 1) * chapters.len() / days;
+            merge_chapters(&chapters[start..end])
+        })
+        .collect()
+}
+
+/// Turn consecutive chapters into passages, e.g. Gen 1, Gen 2, Gen 3 -> "Gen 1-3"
+fn merge_chapters(chapters: &[(&'static str, u32)]) -> Vec<Passage> {
+    let mut passages: Vec<Passage> = Vec::new();
+
+    for (book, chapter) in chapters {
+        match passages.last_mut() {
+            Some(last) if last.book == *book && last.end_chapter + 1 == *chapter => {
+                last.end_chapter = *chapter;
+            }
+            _ => passages.push(Passage {
+                book: book.to_string(),
+                start_chapter: *chapter,
+                start_verse: None,
+                end_chapter: *chapter,
+                end_verse: None,
+            }),
+        }
+    }
+
+    passages
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    /// Count how many times each chapter is assigned in a plan
+    fn chapter_counts(plan: &ReadingPlan) -> HashMap<(String, u32), usize> {
+        let mut counts = HashMap::new();
+        for passage in plan.days.iter().flatten() {
+            for chapter in passage.start_chapter..=passage.end_chapter {
+                *counts.entry((passage.book.clone(), chapter)).or_insert(0) += 1;
+            }
+        }
+        counts
+    }
+
+    #[test]
+    fn test_whole_bible_plans_cover_every_chapter_once() {
+        for plan in [chronological(), canonical_one_year()] {
+            let counts = chapter_counts(&plan);
+            assert_eq!(counts.len(), 1189, "{}", plan.name);
+            assert!(counts.values().all(|count| *count == 1), "{}", plan.name);
+            assert_eq!(plan.len(), 365);
+        }
+    }
+
+    #[test]
+    fn test_mcheyne_reads_new_testament_twice() {
+        let counts = chapter_counts(&mcheyne());
+        assert_eq!(counts[&("Gen".to_string(), 1)], 1);
+        assert_eq!(counts[&("Matt".to_string(), 1)], 2);
+        assert_eq!(counts[&("Rev".to_string(), 22)], 2);
+        assert_eq!(counts.len(), 1189);
+    }
+
+    #[test]
+    fn test_new_testament_90_days() {
+        let plan = new_testament_90_days();
+        assert_eq!(plan.len(), 90);
+        assert_eq!(chapter_counts(&plan).len(), 260);
+        assert_eq!(plan.days[0][0].to_string(), "Matt 1-2");
+    }
+
+    #[test]
+    fn test_user_plan_from_toml() {
+        let plan: ReadingPlan = toml::from_str(
+            r#"
+            name = "Short"
+            days = [["Matt 1-3"], ["Ps 23", "John 3:16-18"]]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(plan.len(), 2);
+        assert_eq!(plan.reading_count(), 3);
+        assert_eq!(plan.days[1][1].to_string(), "John 3:16-18");
+    }
+}
@@ -1,4 +1,6 @@
-use super::Verse;
+use super::strongs::{self, Lexicon};
+use super::{Verse, VerseReference};
+use anyhow::{bail, Result};
 
 /// Search engine for Bible verses using fuzzy matching
 pub struct SearchEngine {
@@ -58,3 +60,268 @@ impl Default for SearchEngine {
         Self::new()
     }
 }
+
+/// Find every case-insensitive occurrence of `query` in `text`
+///
+/// Returns non-overlapping `(start, end)` byte offsets into `text`.
+pub fn match_offsets(text: &str, query: &str) -> Vec<(usize, usize)> {
+    let query: Vec<char> = query.chars().flat_map(char::to_lowercase).collect();
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let mut offsets = Vec::new();
+    let mut next_start = 0;
+
+    for (start, _) in text.char_indices() {
+        if start < next_start {
+            continue;
+        }
+        if let Some(end) = match_at(text, start, &query) {
+            offsets.push((start, end));
+            next_start = end;
+        }
+    }
+
+    offsets
+}
+
+/// What a search looks for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchQuery {
+    /// Verses containing this text
+    Text(String),
+    /// Verses with a word tagged with any of these Strong's numbers
+    Strongs(Vec<String>),
+}
+
+impl SearchQuery {
+    /// Parse search input
+    ///
+    /// `strong:G26` searches by Strong's number and `lemma:agape` by the
+    /// lemma or its transliteration, which needs the lexicon to find the
+    /// numbers. Anything else is a text search.
+    pub fn parse(input: &str, lexicon: Option<&Lexicon>) -> Result<Self> {
+        let input = input.trim();
+
+        if let Some(number) = strip_prefix_ignore_case(input, "strong:") {
+            return match strongs::normalize(number) {
+                Some(number) => Ok(Self::Strongs(vec![number])),
+                None => bail!("Not a Strong's number: {}", number.trim()),
+            };
+        }
+
+        if let Some(lemma) = strip_prefix_ignore_case(input, "lemma:") {
+            let Some(lexicon) = lexicon else {
+                bail!("Lemma search needs a lexicon; run 'biblios import-lexicon <file>'");
+            };
+            let numbers = lexicon.find_lemma(lemma)?;
+            if numbers.is_empty() {
+                bail!("No lemma \"{}\" in the lexicon", lemma.trim());
+            }
+            return Ok(Self::Strongs(numbers));
+        }
+
+        Ok(Self::Text(input.to_string()))
+    }
+}
+
+fn strip_prefix_ignore_case<'a>(input: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = input.get(..prefix.len())?;
+    head.eq_ignore_ascii_case(prefix).then(|| &input[prefix.len()..])
+}
+
+/// Byte offsets of the words in a verse tagged with any of `numbers`
+pub fn strongs_offsets(verse: &Verse, numbers: &[String]) -> Vec<(usize, usize)> {
+    let mut offsets = Vec::new();
+    let mut start = 0;
+    for token in &verse.tokens {
+        let end = start + token.text.len();
+        if token.strongs.iter().any(|number| numbers.contains(number)) {
+            offsets.push((start, end));
+        }
+        start = end;
+    }
+    offsets
+}
+
+/// Group Strong's search results by the English word used to render the lemma
+///
+/// Renderings compare case-insensitively. Groups are ordered by how many
+/// verses use them, and a verse appears once in each group it uses.
+pub fn group_by_rendering(verses: &[Verse], numbers: &[String]) -> Vec<(String, Vec<VerseReference>)> {
+    let mut groups: Vec<(String, Vec<VerseReference>)> = Vec::new();
+
+    for verse in verses {
+        for (start, end) in strongs_offsets(verse, numbers) {
+            let rendering = verse.text[start..end]
+                .trim_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase();
+            let index = match groups.iter().position(|(word, _)| *word == rendering) {
+                Some(index) => index,
+                None => {
+                    groups.push((rendering, Vec::new()));
+                    groups.len() - 1
+                }
+            };
+            let references = &mut groups[index].1;
+            if references.last() != Some(&verse.reference) {
+                references.push(verse.reference.clone());
+            }
+        }
+    }
+
+    groups.sort_by(|a, b| b.1.len().cmp(&a.1.len()).then_with(|| a.0.cmp(&b.0)));
+    groups
+}
+
+/// A word's place in a verse, as byte offsets into its text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Occurrence {
+    pub reference: VerseReference,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Every whole-word occurrence of a word in a translation, grouped by book
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Concordance {
+    pub word: String,
+    /// Books in Bible order, each with its occurrences
+    pub books: Vec<(String, Vec<Occurrence>)>,
+}
+
+impl Concordance {
+    /// Build a concordance from the verses containing `word`, in Bible order
+    pub fn build(word: &str, verses: &[Verse]) -> Self {
+        let mut books: Vec<(String, Vec<Occurrence>)> = Vec::new();
+
+        for verse in verses {
+            for (start, end) in word_offsets(&verse.text, word) {
+                let occurrence = Occurrence {
+                    reference: verse.reference.clone(),
+                    start,
+                    end,
+                };
+                match books.last_mut() {
+                    Some((book, occurrences)) if *book == verse.reference.book => occurrences.push(occurrence),
+                    _ => books.push((verse.reference.book.clone(), vec![occurrence])),
+                }
+            }
+        }
+
+        Self {
+            word: word.to_string(),
+            books,
+        }
+    }
+
+    /// Occurrences across all books, in Bible order
+    pub fn occurrences(&self) -> impl Iterator<Item = &Occurrence> {
+        self.books.iter().flat_map(|(_, occurrences)| occurrences)
+    }
+
+    pub fn total(&self) -> usize {
+        self.books.iter().map(|(_, occurrences)| occurrences.len()).sum()
+    }
+}
+
+/// Whole-word, case-insensitive occurrences of `word` in `text`, as byte offsets
+pub fn word_offsets(text: &str, word: &str) -> Vec<(usize, usize)> {
+    match_offsets(text, word)
+        .into_iter()
+        .filter(|&(start, end)| {
+            let before = text[..start].chars().next_back();
+            let after = text[end..].chars().next();
+            !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+        })
+        .collect()
+}
+
+/// Split a verse around the word at byte offsets `start..end` for a
+/// keyword-in-context line, keeping up to `width` chars of context each side
+pub fn kwic(text: &str, start: usize, end: usize, width: usize) -> (String, &str, String) {
+    let before: Vec<char> = text[..start].chars().collect();
+    let left = before[before.len().saturating_sub(width)..].iter().collect();
+    let right = text[end..].chars().take(width).collect();
+    (left, &text[start..end], right)
+}
+
+/// Match lowercased `query` against `text` starting at byte `start`
+fn match_at(text: &str, start: usize, query: &[char]) -> Option<usize> {
+    let mut matched = 0;
+
+    for (idx, c) in text[start..].char_indices() {
+        for lower in c.to_lowercase() {
+            if query.get(matched) != Some(&lower) {
+                return None;
+            }
+            matched += 1;
+        }
+        if matched == query.len() {
+            return Some(start + idx + c.len_utf8());
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_match_offsets() {
+        let text = "God is love; LOVE never faileth";
+        assert_eq!(match_offsets(text, "love"), vec![(7, 11), (13, 17)]);
+        assert_eq!(match_offsets(text, "hate"), vec![]);
+        assert_eq!(match_offsets(text, ""), vec![]);
+    }
+
+    #[test]
+    fn test_concordance() {
+        let verses = vec![
+            Verse::from_text(VerseReference::new("Gen", 1, 1), "God created the heaven".to_string()),
+            Verse::from_text(VerseReference::new("Gen", 1, 3), "And God said, Let there be light: godly".to_string()),
+            Verse::from_text(VerseReference::new("John", 1, 1), "the Word was with God, and the Word was God.".to_string()),
+        ];
+
+        let concordance = Concordance::build("god", &verses);
+        assert_eq!(concordance.total(), 4);
+        let counts: Vec<(&str, usize)> = concordance
+            .books
+            .iter()
+            .map(|(book, occurrences)| (book.as_str(), occurrences.len()))
+            .collect();
+        assert_eq!(counts, vec![("Gen", 2), ("John", 2)]);
+
+        let occurrence = concordance.occurrences().nth(1).unwrap();
+        assert_eq!(occurrence.reference, verses[1].reference);
+        assert_eq!(
+            kwic(&verses[1].text, occurrence.start, occurrence.end, 4),
+            ("And ".to_string(), "God", " sai".to_string())
+        );
+    }
+
+    #[test]
+    fn test_strongs_query_and_grouping() {
+        assert_eq!(
+            SearchQuery::parse("strong:g0026", None).unwrap(),
+            SearchQuery::Strongs(vec!["G26".to_string()])
+        );
+        assert_eq!(SearchQuery::parse("charity", None).unwrap(), SearchQuery::Text("charity".to_string()));
+        assert!(SearchQuery::parse("lemma:agape", None).is_err());
+
+        let verses = vec![
+            Verse::from_text(VerseReference::new("1Cor", 13, 4), "Charity<G26> suffereth long, charity<G26> envieth not".to_string()),
+            Verse::from_text(VerseReference::new("1John", 4, 8), "for God is love<G26>.".to_string()),
+            Verse::from_text(VerseReference::new("1John", 4, 16), "God is love<G26>; and he that dwelleth in love<G26>".to_string()),
+        ];
+        let numbers = vec!["G26".to_string()];
+        assert_eq!(strongs_offsets(&verses[1], &numbers), vec![(11, 15)]);
+
+        let groups = group_by_rendering(&verses, &numbers);
+        assert_eq!(groups[0], ("love".to_string(), vec![verses[1].reference.clone(), verses[2].reference.clone()]));
+        assert_eq!(groups[1], ("charity".to_string(), vec![verses[0].reference.clone()]));
+    }
+}
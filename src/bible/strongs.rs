@@ -0,0 +1,401 @@
+use anyhow::{Context, Result};
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// A piece of verse text with the Strong's numbers tagged on it
+///
+/// Untagged text between words (spaces, punctuation, untagged words) is kept
+/// as tokens with no numbers, so joining every token's text gives back the
+/// plain verse.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Token {
+    pub text: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub strongs: Vec<String>,
+}
+
+/// Split tagged verse text into tokens
+///
+/// Understands inline tags after a word, `In the beginning<H7225> God<H430>`
+/// (also `{H430}`), and OSIS markup, `<w lemma="strong:G25">loved</w>`.
+/// Notes are skipped and other markup is dropped.
+pub fn parse_tagged(text: &str) -> Vec<Token> {
+    let mut tokens: Vec<Token> = Vec::new();
+    let mut plain = String::new();
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        // OSIS word element
+        if rest.starts_with("<w ") || rest.starts_with("<w>") {
+            let Some(open_end) = rest.find('>') else { break };
+            let attrs = &rest[..open_end];
+            let body_start = open_end + 1;
+            let body_end = rest[body_start..]
+                .find("</w>")
+                .map(|pos| body_start + pos)
+                .unwrap_or(rest.len());
+
+            flush(&mut tokens, &mut plain);
+            tokens.push(Token {
+                text: strip_markup(&rest[body_start..body_end]),
+                strongs: osis_lemmas(attrs),
+            });
+            rest = &rest[(body_end + 4).min(rest.len())..];
+            continue;
+        }
+
+        // Inline tag: attach to the word just before it
+        if let Some((numbers, len)) = tag_at(rest) {
+            let word_start = plain
+                .char_indices()
+                .rev()
+                .take_while(|(_, c)| !c.is_whitespace())
+                .last()
+                .map(|(idx, _)| idx)
+                .unwrap_or(plain.len());
+            let word = plain.split_off(word_start);
+            flush(&mut tokens, &mut plain);
+
+            // Several tags in a row all belong to the same word
+            match tokens.last_mut() {
+                Some(last) if word.is_empty() && !last.strongs.is_empty() => {
+                    last.strongs.extend(numbers)
+                }
+                _ => tokens.push(Token { text: word, strongs: numbers }),
+            }
+            rest = &rest[len..];
+            continue;
+        }
+
+        // Notes aren't part of the verse text
+        if rest.starts_with("<note") {
+            rest = rest
+                .find("</note>")
+                .map(|end| &rest[end + 7..])
+                .unwrap_or("");
+            continue;
+        }
+
+        // Any other markup element is dropped
+        if c == '<' {
+            if let Some(end) = rest.find('>') {
+                rest = &rest[end + 1..];
+                continue;
+            }
+        }
+
+        plain.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+
+    flush(&mut tokens, &mut plain);
+    tokens
+}
+
+/// Plain text of a token list
+pub fn plain_text(tokens: &[Token]) -> String {
+    tokens.iter().map(|t| t.text.as_str()).collect()
+}
+
+/// Strong's numbers on the token covering a char offset
+pub fn strongs_at(tokens: &[Token], offset: usize) -> &[String] {
+    let mut start = 0;
+    for token in tokens {
+        let end = start + token.text.chars().count();
+        if offset < end {
+            return &token.strongs;
+        }
+        start = end;
+    }
+    &[]
+}
+
+/// Canonical form of a Strong's number: `h0430` -> `H430`
+pub fn normalize(number: &str) -> Option<String> {
+    let number = number.trim();
+    let prefix = number.chars().next()?.to_ascii_uppercase();
+    if prefix != 'H' && prefix != 'G' {
+        return None;
+    }
+    let digits: u32 = number[1..].trim_end_matches(|c: char| c.is_ascii_alphabetic()).parse().ok()?;
+    Some(format!("{}{}", prefix, digits))
+}
+
+fn flush(tokens: &mut Vec<Token>, plain: &mut String) {
+    if !plain.is_empty() {
+        tokens.push(Token {
+            text: std::mem::take(plain),
+            strongs: Vec::new(),
+        });
+    }
+}
+
+/// An inline `<H430>` or `{G25}` tag at the start of `rest`: its numbers and length
+fn tag_at(rest: &str) -> Option<(Vec<String>, usize)> {
+    let close = match rest.chars().next()? {
+        '<' => '>',
+        '{' => '}',
+        _ => return None,
+    };
+    let end = rest.find(close)?;
+    let inner = &rest[1..end];
+
+    // Allow "H430 H853" inside one tag
+    let numbers: Option<Vec<String>> = inner.split_whitespace().map(normalize).collect();
+    let numbers = numbers.filter(|numbers| !numbers.is_empty())?;
+    Some((numbers, end + 1))
+}
+
+/// Strong's numbers from an OSIS `lemma="strong:H7225 strong:H430"` attribute
+fn osis_lemmas(attrs: &str) -> Vec<String> {
+    let Some(start) = attrs.find("lemma=\"") else {
+        return Vec::new();
+    };
+    let value = &attrs[start + 7..];
+    let value = &value[..value.find('"').unwrap_or(value.len())];
+
+    value
+        .split_whitespace()
+        .filter_map(|lemma| lemma.strip_prefix("strong:"))
+        .filter_map(normalize)
+        .collect()
+}
+
+fn strip_markup(text: &str) -> String {
+    let mut plain = String::new();
+    let mut in_tag = false;
+    for c in text.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => plain.push(c),
+            _ => {}
+        }
+    }
+    plain
+}
+
+/// Lowercase a lemma or transliteration and strip its accents and breathing
+/// marks, so `ʼĕlôhîym` compares equal to `elohiym`
+fn fold(text: &str) -> String {
+    text.trim()
+        .chars()
+        .flat_map(char::to_lowercase)
+        .filter_map(|c| match c {
+            'á' | 'à' | 'â' | 'ā' | 'ă' | 'ä' => Some('a'),
+            'é' | 'è' | 'ê' | 'ē' | 'ĕ' | 'ë' => Some('e'),
+            'í' | 'ì' | 'î' | 'ī' | 'ĭ' | 'ï' => Some('i'),
+            'ó' | 'ò' | 'ô' | 'ō' | 'ŏ' | 'ö' => Some('o'),
+            'ú' | 'ù' | 'û' | 'ū' | 'ŭ' | 'ü' => Some('u'),
+            'ý' | 'ŷ' | 'ȳ' => Some('y'),
+            'ç' => Some('c'),
+            'ṭ' => Some('t'),
+            'ṣ' | 'ś' | 'š' => Some('s'),
+            'ḥ' => Some('h'),
+            'ʼ' | 'ʽ' | '\'' | '’' | '‘' | '-' => None,
+            // Greek and Hebrew diacritics are combining marks
+            c if ('\u{0300}'..='\u{036f}').contains(&c) || ('\u{0591}'..='\u{05c7}').contains(&c) => None,
+            c => Some(c),
+        })
+        .collect()
+}
+
+/// A Strong's dictionary entry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LexiconEntry {
+    /// Normalized number, e.g. "H430"
+    pub number: String,
+    /// Original-language word
+    pub lemma: String,
+    pub transliteration: String,
+    pub pronunciation: String,
+    pub definition: String,
+    /// How the KJV renders the word
+    pub kjv_usage: String,
+}
+
+/// Entry as found in the Open Scriptures Strong's dictionary files
+#[derive(Debug, Deserialize)]
+struct RawEntry {
+    #[serde(default)]
+    lemma: String,
+    #[serde(default)]
+    xlit: String,
+    #[serde(default)]
+    translit: String,
+    #[serde(default)]
+    pron: String,
+    #[serde(default)]
+    strongs_def: String,
+    #[serde(default)]
+    derivation: String,
+    #[serde(default)]
+    kjv_def: String,
+}
+
+/// Strong's Hebrew and Greek lexicon stored in its own SQLite database
+pub struct Lexicon {
+    conn: Connection,
+}
+
+impl Lexicon {
+    /// Open (creating if needed) the lexicon database
+    pub fn open(db_path: impl AsRef<Path>) -> Result<Self> {
+        let conn = Connection::open(db_path)?;
+
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS lexicon (
+                number TEXT PRIMARY KEY,
+                lemma TEXT NOT NULL,
+                transliteration TEXT NOT NULL,
+                pronunciation TEXT NOT NULL,
+                definition TEXT NOT NULL,
+                kjv_usage TEXT NOT NULL
+            )",
+            [],
+        )?;
+
+        Ok(Self { conn })
+    }
+
+    /// Look up a Strong's number
+    pub fn get(&self, number: &str) -> Result<Option<LexiconEntry>> {
+        let Some(number) = normalize(number) else {
+            return Ok(None);
+        };
+
+        let result = self.conn.query_row(
+            "SELECT number, lemma, transliteration, pronunciation, definition, kjv_usage
+             FROM lexicon WHERE number = ?1",
+            params![number],
+            |row| {
+                Ok(LexiconEntry {
+                    number: row.get(0)?,
+                    lemma: row.get(1)?,
+                    transliteration: row.get(2)?,
+                    pronunciation: row.get(3)?,
+                    definition: row.get(4)?,
+                    kjv_usage: row.get(5)?,
+                })
+            },
+        );
+
+        match result {
+            Ok(entry) => Ok(Some(entry)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Strong's numbers whose lemma or transliteration matches `term`
+    ///
+    /// Matching ignores case and accents, so `agape` finds G26 (agápē).
+    pub fn find_lemma(&self, term: &str) -> Result<Vec<String>> {
+        let term = fold(term);
+        if term.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut stmt = self.conn.prepare("SELECT number, lemma, transliteration FROM lexicon")?;
+        let rows = stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?, row.get::<_, String>(2)?))
+        })?;
+
+        let mut numbers = Vec::new();
+        for row in rows {
+            let (number, lemma, transliteration) = row?;
+            if fold(&lemma) == term || fold(&transliteration) == term {
+                numbers.push(number);
+            }
+        }
+        Ok(numbers)
+    }
+
+    /// Add or replace entries from an Open Scriptures Strong's dictionary
+    ///
+    /// The input is a JSON object keyed by number, e.g.
+    /// `{"H430": {"lemma": "...", "xlit": "...", "strongs_def": "..."}}`.
+    /// The published `.js` files wrap that object in a variable assignment,
+    /// which is stripped. Returns how many entries were imported.
+    pub fn import(&mut self, input: &str) -> Result<usize> {
+        let start = input.find('{').context("No JSON object found in lexicon file")?;
+        let end = input.rfind('}').context("No JSON object found in lexicon file")?;
+        let entries: HashMap<String, RawEntry> = serde_json::from_str(&input[start..=end])
+            .context("Failed to parse lexicon JSON")?;
+
+        let tx = self.conn.transaction()?;
+        let mut count = 0;
+        {
+            let mut stmt = tx.prepare(
+                "INSERT OR REPLACE INTO lexicon
+                 (number, lemma, transliteration, pronunciation, definition, kjv_usage)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)"
+            )?;
+
+            for (number, entry) in entries {
+                let Some(number) = normalize(&number) else {
+                    continue;
+                };
+                let transliteration = if entry.xlit.is_empty() { entry.translit } else { entry.xlit };
+                let definition = [entry.strongs_def.trim(), entry.derivation.trim()]
+                    .iter()
+                    .filter(|part| !part.is_empty())
+                    .copied()
+                    .collect::<Vec<_>>()
+                    .join(" ");
+
+                stmt.execute(params![
+                    number,
+                    entry.lemma,
+                    transliteration,
+                    entry.pron,
+                    definition,
+                    entry.kjv_def.trim(),
+                ])?;
+                count += 1;
+            }
+        }
+
+        tx.commit()?;
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_inline_and_osis_tags() {
+        let tokens = parse_tagged("In the beginning<H7225> God<H430> created<H1254><H853> the heaven,");
+        assert_eq!(plain_text(&tokens), "In the beginning God created the heaven,");
+        assert_eq!(strongs_at(&tokens, 7), ["H7225"]);
+        assert_eq!(strongs_at(&tokens, 17), ["H430"]);
+        assert_eq!(strongs_at(&tokens, 21), ["H1254", "H853"]);
+        assert!(strongs_at(&tokens, 0).is_empty());
+
+        let osis = parse_tagged(r#"For <w lemma="strong:G1063">God</w> so <w lemma="strong:G0025">loved</w><note>x</note>"#);
+        assert_eq!(plain_text(&osis), "For God so loved");
+        assert_eq!(strongs_at(&osis, 5), ["G1063"]);
+        assert_eq!(strongs_at(&osis, 12), ["G25"]);
+
+        assert_eq!(normalize("h0430"), Some("H430".to_string()));
+    }
+
+    #[test]
+    fn test_lexicon_import() {
+        let data = r#"var strongsHebrewDictionary = {"H430":{"lemma":"אֱלֹהִים","xlit":"ʼĕlôhîym","pron":"el-o-heem'","derivation":"plural of H433;","strongs_def":"gods in the ordinary sense","kjv_def":"angels, God"}};"#;
+        let mut lexicon = Lexicon::open(":memory:").unwrap();
+        assert_eq!(lexicon.import(data).unwrap(), 1);
+
+        let entry = lexicon.get("H0430").unwrap().unwrap();
+        assert_eq!(entry.transliteration, "ʼĕlôhîym");
+        assert_eq!(entry.definition, "gods in the ordinary sense plural of H433;");
+        assert!(lexicon.get("G25").unwrap().is_none());
+
+        assert_eq!(lexicon.find_lemma("Elohiym").unwrap(), ["H430"]);
+        assert!(lexicon.find_lemma("agape").unwrap().is_empty());
+    }
+}
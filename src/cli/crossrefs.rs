@@ -0,0 +1,28 @@
+use super::EXIT_SUCCESS;
+use crate::bible::crossrefs::CrossRefIndex;
+use crate::config;
+use anyhow::{Context, Result};
+use clap::Args;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::PathBuf;
+
+/// Arguments for `biblios import-crossrefs`
+#[derive(Debug, Args)]
+pub struct ImportCrossRefsArgs {
+    /// Cross-reference file in OpenBible.info / TSK tab-separated format
+    pub file: PathBuf,
+}
+
+/// Import a cross-reference dataset, replacing any previous import
+pub fn run(args: &ImportCrossRefsArgs) -> Result<i32> {
+    let file = File::open(&args.file)
+        .with_context(|| format!("Failed to open {}", args.file.display()))?;
+
+    let path = config::crossrefs_path()?;
+    let mut index = CrossRefIndex::open(&path)?;
+    let count = index.import(BufReader::new(file))?;
+
+    println!("Imported {} cross-references into {}", count, path.display());
+    Ok(EXIT_SUCCESS)
+}
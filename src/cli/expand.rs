@@ -0,0 +1,121 @@
+use super::EXIT_SUCCESS;
+use crate::bible::loader::BibleLoader;
+use crate::bible::parser::find_passages;
+use crate::bible::Passage;
+use anyhow::{Context, Result};
+use clap::{Args, ValueEnum};
+use std::io::{self, Read, Write};
+
+/// Default link pattern for `--template link`
+const DEFAULT_LINK_URL: &str =
+    "https://www.biblegateway.com/passage/?search={reference}&version={translation}";
+
+/// Arguments for `biblios expand`
+#[derive(Debug, Args)]
+pub struct ExpandArgs {
+    /// How each reference found in the input is rewritten
+    #[arg(long, value_enum, default_value_t = Template::Expand)]
+    pub template: Template,
+
+    /// URL pattern for `--template link`; {reference} and {translation} are substituted
+    #[arg(long, default_value = DEFAULT_LINK_URL)]
+    pub url: String,
+}
+
+/// Ways to rewrite a reference found in the input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Template {
+    /// Follow the reference with the quoted verse text
+    Expand,
+    /// Turn the reference into a Markdown link
+    Link,
+    /// Add a Markdown footnote with the verse text at the end
+    Footnote,
+}
+
+/// Read text on stdin and write it back with every reference rewritten
+pub fn run(loader: &BibleLoader, translation: &str, args: &ExpandArgs) -> Result<i32> {
+    let mut input = String::new();
+    io::stdin()
+        .read_to_string(&mut input)
+        .context("Failed to read stdin")?;
+
+    write!(io::stdout().lock(), "{}", expand(loader, translation, args, &input)?)?;
+    Ok(EXIT_SUCCESS)
+}
+
+/// Rewrite each reference in `input` according to the template
+fn expand(loader: &BibleLoader, translation: &str, args: &ExpandArgs, input: &str) -> Result<String> {
+    let mut output = String::with_capacity(input.len());
+    let mut footnotes = Vec::new();
+    let mut last = 0;
+
+    for (range, passage) in find_passages(input) {
+        let original = &input[range.clone()];
+        output.push_str(&input[last..range.start]);
+        last = range.end;
+
+        let text = passage_text(loader, &passage)?;
+        match (args.template, text) {
+            (Template::Expand, Some(text)) => {
+                output.push_str(&format!("{} \"{}\"", original, text));
+            }
+            (Template::Footnote, Some(text)) => {
+                footnotes.push(format!("{} ({}) {}", passage, translation, text));
+                output.push_str(&format!("{}[^{}]", original, footnotes.len()));
+            }
+            (Template::Link, _) => {
+                let url = args
+                    .url
+                    .replace("{reference}", &url_encode(&passage.to_string()))
+                    .replace("{translation}", &url_encode(translation));
+                output.push_str(&format!("[{}]({})", original, url));
+            }
+            // Leave references we have no text for untouched
+            (_, None) => output.push_str(original),
+        }
+    }
+
+    output.push_str(&input[last..]);
+
+    if !footnotes.is_empty() {
+        if !output.ends_with('\n') {
+            output.push('\n');
+        }
+        output.push('\n');
+        for (idx, note) in footnotes.iter().enumerate() {
+            output.push_str(&format!("[^{}]: {}\n", idx + 1, note));
+        }
+    }
+
+    Ok(output)
+}
+
+/// Load a passage's verses joined into a single line of text
+fn passage_text(loader: &BibleLoader, passage: &Passage) -> Result<Option<String>> {
+    let verses = loader.load_passage(passage)?;
+    if verses.is_empty() {
+        return Ok(None);
+    }
+
+    let text = verses
+        .iter()
+        .map(|verse| verse.text.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    Ok(Some(text))
+}
+
+/// Percent-encode a URL component
+fn url_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
@@ -0,0 +1,29 @@
+use super::EXIT_SUCCESS;
+use crate::bible::strongs::Lexicon;
+use crate::config;
+use anyhow::{Context, Result};
+use clap::Args;
+use std::fs;
+use std::path::PathBuf;
+
+/// Arguments for `biblios import-lexicon`
+#[derive(Debug, Args)]
+pub struct ImportLexiconArgs {
+    /// Strong's dictionary file, e.g. strongs-hebrew-dictionary.js
+    pub file: PathBuf,
+}
+
+/// Import a Strong's dictionary, adding to any entries already imported
+///
+/// Hebrew and Greek come as separate files, so run this once for each.
+pub fn run(args: &ImportLexiconArgs) -> Result<i32> {
+    let input = fs::read_to_string(&args.file)
+        .with_context(|| format!("Failed to read {}", args.file.display()))?;
+
+    let path = config::lexicon_path()?;
+    let mut lexicon = Lexicon::open(&path)?;
+    let count = lexicon.import(&input)?;
+
+    println!("Imported {} lexicon entries into {}", count, path.display());
+    Ok(EXIT_SUCCESS)
+}
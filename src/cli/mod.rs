@@ -0,0 +1,152 @@
+pub mod crossrefs;
+pub mod expand;
+pub mod lexicon;
+pub mod module;
+pub mod passage;
+pub mod search;
+pub mod today;
+
+use crate::bible::loader::BibleLoader;
+use crate::config::{self, Settings};
+use anyhow::{bail, Result};
+use clap::{Parser, Subcommand, ValueEnum};
+
+/// Command-line interface for Biblios
+///
+/// With no arguments Biblios starts the interactive reader. Passing a
+/// reference prints the passage to stdout and exits, which makes it easy to
+/// use Biblios from scripts and editors.
+#[derive(Debug, Parser)]
+#[command(name = "biblios", version, about = "A full-featured TUI Bible reader")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Translation to read from (defaults to the configured translation)
+    #[arg(short, long, global = true)]
+    pub translation: Option<String>,
+
+    /// Output format for non-interactive commands
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Text, global = true)]
+    pub format: OutputFormat,
+
+    /// Passage to print, e.g. "John 3:16-18", "Ps 23" or "Rom 8"
+    pub reference: Vec<String>,
+}
+
+/// Non-interactive subcommands
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Search verse text and print the matches
+    Search(search::SearchArgs),
+
+    /// Read text on stdin and expand, link or footnote every reference in it
+    Expand(expand::ExpandArgs),
+
+    /// Print the verse of the day
+    Today(today::TodayArgs),
+
+    /// Import a cross-reference dataset (OpenBible.info / TSK format)
+    ImportCrossrefs(crossrefs::ImportCrossRefsArgs),
+
+    /// Import a Strong's Hebrew or Greek lexicon (Open Scriptures JSON)
+    ImportLexicon(lexicon::ImportLexiconArgs),
+
+    /// Import a commentary, dictionary or topical index (JSON or SQLite)
+    ImportModule(module::ImportModuleArgs),
+}
+
+/// Output formats for non-interactive commands
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable plain text
+    Text,
+    /// JSON for scripts and editor integrations
+    Json,
+    /// Comma-separated values with a header row
+    Csv,
+}
+
+/// Exit code when a command succeeds
+pub const EXIT_SUCCESS: i32 = 0;
+
+/// Exit code when a search or lookup finds nothing
+pub const EXIT_NO_MATCH: i32 = 1;
+
+/// Exit code when a command fails
+pub const EXIT_ERROR: i32 = 2;
+
+impl Cli {
+    /// Whether this invocation should run without starting the TUI
+    pub fn is_non_interactive(&self) -> bool {
+        self.command.is_some() || !self.reference.is_empty()
+    }
+
+    /// Resolve the translation to use, falling back to the saved setting
+    fn translation(&self) -> Result<String> {
+        match &self.translation {
+            Some(translation) => Ok(translation.to_uppercase()),
+            None => Ok(Settings::load()?.translation),
+        }
+    }
+}
+
+/// Run a non-interactive command, returning the process exit code
+pub fn run(cli: Cli) -> Result<i32> {
+    let result = match &cli.command {
+        Some(Command::Search(args)) => with_translation(&cli, |loader, _| search::run(loader, args, cli.format)),
+        Some(Command::Expand(args)) => with_translation(&cli, |loader, translation| expand::run(loader, translation, args)),
+        Some(Command::Today(args)) => {
+            with_translation(&cli, |loader, translation| today::run(loader, translation, args, cli.format))
+        }
+        // Importing doesn't read any translation
+        Some(Command::ImportCrossrefs(args)) => crossrefs::run(args),
+        Some(Command::ImportLexicon(args)) => lexicon::run(args),
+        Some(Command::ImportModule(args)) => module::run(args),
+        None => with_translation(&cli, |loader, translation| {
+            passage::run(loader, translation, &cli.reference.join(" "), cli.format)
+        }),
+    };
+
+    // A reader like `head` closing the pipe early isn't an error
+    match result {
+        Err(err) if is_broken_pipe(&err) => Ok(EXIT_SUCCESS),
+        result => result,
+    }
+}
+
+/// Open the selected translation and run a command against it
+fn with_translation(cli: &Cli, command: impl FnOnce(&BibleLoader, &str) -> Result<i32>) -> Result<i32> {
+    let translation = cli.translation()?;
+    let loader = open_translation(&translation)?;
+    command(&loader, &translation)
+}
+
+/// Whether an error comes from writing to a closed pipe
+fn is_broken_pipe(err: &anyhow::Error) -> bool {
+    err.downcast_ref::<std::io::Error>()
+        .is_some_and(|err| err.kind() == std::io::ErrorKind::BrokenPipe)
+}
+
+/// Open the database for an installed translation
+fn open_translation(translation: &str) -> Result<BibleLoader> {
+    let path = config::translation_path(translation)?;
+    if !path.exists() {
+        bail!(
+            "Translation {} not found at: {}",
+            translation,
+            path.display()
+        );
+    }
+
+    BibleLoader::new(path)
+}
+
+/// Quote a field for CSV output when it contains separators or quotes
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
@@ -0,0 +1,64 @@
+use super::EXIT_SUCCESS;
+use crate::bible::modules::{ModuleFile, ModuleInfo, ModuleKind, ModuleStore};
+use crate::config;
+use anyhow::{Context, Result};
+use clap::Args;
+use std::fs;
+use std::path::PathBuf;
+
+/// Arguments for `biblios import-module`
+#[derive(Debug, Args)]
+pub struct ImportModuleArgs {
+    /// Module file: JSON, or SQLite (.sqlite/.db) with an entries table
+    pub file: PathBuf,
+
+    /// Short id for the module (defaults to the file name)
+    #[arg(long)]
+    pub id: Option<String>,
+
+    /// Display name (defaults to the name in the file, or the id)
+    #[arg(long)]
+    pub name: Option<String>,
+
+    /// Kind of module, if the file doesn't say
+    #[arg(long, value_enum)]
+    pub kind: Option<ModuleKind>,
+}
+
+/// Import a commentary, dictionary or topical index, replacing any module
+/// with the same id
+pub fn run(args: &ImportModuleArgs) -> Result<i32> {
+    let is_sqlite = args
+        .file
+        .extension()
+        .is_some_and(|ext| ext == "sqlite" || ext == "db");
+    let file = if is_sqlite {
+        ModuleFile::from_sqlite(&args.file)?
+    } else {
+        let input = fs::read_to_string(&args.file)
+            .with_context(|| format!("Failed to read {}", args.file.display()))?;
+        ModuleFile::from_json(&input)?
+    };
+
+    let id = args
+        .id
+        .clone()
+        .or_else(|| file.id.clone())
+        .or_else(|| args.file.file_stem().map(|stem| stem.to_string_lossy().to_lowercase()))
+        .context("Module id not given; pass --id")?;
+    let info = ModuleInfo {
+        name: args.name.clone().or_else(|| file.name.clone()).unwrap_or_else(|| id.clone()),
+        kind: args
+            .kind
+            .or(file.kind)
+            .context("Module kind not given; pass --kind commentary, dictionary or topical")?,
+        id,
+    };
+
+    let path = config::modules_path()?;
+    let mut store = ModuleStore::open(&path)?;
+    let count = store.import(&info, &file)?;
+
+    println!("Imported {} entries of {} ({}) into {}", count, info.name, info.id, path.display());
+    Ok(EXIT_SUCCESS)
+}
@@ -0,0 +1,124 @@
+use super::{csv_field, OutputFormat, EXIT_SUCCESS};
+use crate::bible::loader::BibleLoader;
+use crate::bible::parser::parse_passage;
+use crate::bible::versification::verse_count;
+use crate::bible::{Passage, Verse};
+use anyhow::{bail, Context, Result};
+use serde::Serialize;
+use std::io::{self, Write};
+
+/// JSON representation of a printed passage
+#[derive(Serialize)]
+struct PassageOutput<'a> {
+    reference: String,
+    translation: &'a str,
+    verses: Vec<VerseOutput<'a>>,
+}
+
+/// JSON representation of a single verse
+#[derive(Serialize)]
+struct VerseOutput<'a> {
+    book: &'a str,
+    chapter: u32,
+    verse: u32,
+    text: &'a str,
+}
+
+/// Print a passage to stdout
+pub fn run(loader: &BibleLoader, translation: &str, input: &str, format: OutputFormat) -> Result<i32> {
+    let passage = parse_passage(input)
+        .with_context(|| format!("Could not understand reference: {}", input))?;
+    validate(&passage)?;
+
+    let verses = loader.load_passage(&passage)?;
+    if verses.is_empty() {
+        bail!("No verses found for {} in {}", passage, translation);
+    }
+
+    print(&passage, translation, &verses, format)?;
+    Ok(EXIT_SUCCESS)
+}
+
+/// Print loaded verses in the requested format
+pub fn print(passage: &Passage, translation: &str, verses: &[Verse], format: OutputFormat) -> Result<()> {
+    let mut out = io::stdout().lock();
+    match format {
+        OutputFormat::Text => print_text(&mut out, passage, translation, verses)?,
+        OutputFormat::Json => print_json(&mut out, passage, translation, verses)?,
+        OutputFormat::Csv => print_csv(&mut out, verses)?,
+    }
+
+    Ok(())
+}
+
+/// Reject chapters and verses that don't exist in the book
+fn validate(passage: &Passage) -> Result<()> {
+    let points = [
+        (passage.start_chapter, passage.start_verse),
+        (passage.end_chapter, passage.end_verse),
+    ];
+
+    for (chapter, verse) in points {
+        let count = verse_count(&passage.book, chapter)
+            .with_context(|| format!("{} has no chapter {}", passage.book, chapter))?;
+        if let Some(verse) = verse {
+            if verse == 0 || verse > count {
+                bail!("{} {} has no verse {}", passage.book, chapter, verse);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Print verses as plain text, one per line, under a reference heading
+fn print_text(out: &mut impl Write, passage: &Passage, translation: &str, verses: &[Verse]) -> io::Result<()> {
+    let multi_chapter = passage.start_chapter != passage.end_chapter;
+
+    writeln!(out, "{} ({})", passage, translation)?;
+    writeln!(out)?;
+    for verse in verses {
+        if multi_chapter {
+            writeln!(out, "{}:{} {}", verse.reference.chapter, verse.reference.verse, verse.text)?;
+        } else {
+            writeln!(out, "{} {}", verse.reference.verse, verse.text)?;
+        }
+    }
+    Ok(())
+}
+
+/// Print the passage as a single JSON object
+fn print_json(out: &mut impl Write, passage: &Passage, translation: &str, verses: &[Verse]) -> Result<()> {
+    let output = PassageOutput {
+        reference: passage.to_string(),
+        translation,
+        verses: verses
+            .iter()
+            .map(|verse| VerseOutput {
+                book: &verse.reference.book,
+                chapter: verse.reference.chapter,
+                verse: verse.reference.verse,
+                text: &verse.text,
+            })
+            .collect(),
+    };
+
+    writeln!(out, "{}", serde_json::to_string_pretty(&output)?)?;
+    Ok(())
+}
+
+/// Print one CSV row per verse
+fn print_csv(out: &mut impl Write, verses: &[Verse]) -> io::Result<()> {
+    writeln!(out, "book,chapter,verse,text")?;
+    for verse in verses {
+        writeln!(
+            out,
+            "{},{},{},{}",
+            verse.reference.book,
+            verse.reference.chapter,
+            verse.reference.verse,
+            csv_field(&verse.text)
+        )?;
+    }
+    Ok(())
+}
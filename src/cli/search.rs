@@ -0,0 +1,135 @@
+use super::{csv_field, OutputFormat, EXIT_NO_MATCH, EXIT_SUCCESS};
+use crate::bible::loader::BibleLoader;
+use crate::bible::parser::resolve_book;
+use crate::bible::search::{match_offsets, strongs_offsets, SearchQuery};
+use crate::bible::strongs::Lexicon;
+use crate::bible::Verse;
+use crate::config::{self, Settings};
+use anyhow::{Context, Result};
+use clap::Args;
+use serde::Serialize;
+use std::io::{self, Write};
+
+/// Arguments for `biblios search`
+#[derive(Debug, Args)]
+pub struct SearchArgs {
+    /// Text to search for (case-insensitive), or `strong:G26` / `lemma:agape`
+    /// for words tagged with a Strong's number
+    pub query: String,
+
+    /// Only search within this book, e.g. "John" or "Ps"
+    #[arg(short, long)]
+    pub book: Option<String>,
+
+    /// Maximum number of results (defaults to the configured search limit)
+    #[arg(short, long)]
+    pub limit: Option<usize>,
+}
+
+/// JSON representation of a search hit
+#[derive(Serialize)]
+struct SearchHit<'a> {
+    reference: String,
+    book: &'a str,
+    chapter: u32,
+    verse: u32,
+    text: &'a str,
+    matches: Vec<MatchOffset>,
+}
+
+/// Byte offsets of a match within the verse text
+#[derive(Serialize)]
+struct MatchOffset {
+    start: usize,
+    end: usize,
+}
+
+/// Search the translation and print each matching verse
+///
+/// Returns `EXIT_NO_MATCH` when nothing matched, like `grep`.
+pub fn run(
+    loader: &BibleLoader,
+    args: &SearchArgs,
+    format: OutputFormat,
+) -> Result<i32> {
+    let book = match &args.book {
+        Some(name) => Some(resolve_book(name).with_context(|| format!("Unknown book: {}", name))?),
+        None => None,
+    };
+    let limit = match args.limit {
+        Some(limit) => limit,
+        None => Settings::load()?.search_limit,
+    };
+
+    // Lemma searches look the lemma up in the lexicon, if one is imported
+    let lexicon = config::lexicon_path()?;
+    let lexicon = if lexicon.exists() { Some(Lexicon::open(lexicon)?) } else { None };
+    let query = SearchQuery::parse(&args.query, lexicon.as_ref())?;
+
+    let verses = loader.search(&query, book, Some(limit))?;
+    let hits: Vec<SearchHit> = verses
+        .iter()
+        .map(|verse| to_hit(verse, &query))
+        .collect();
+
+    let mut out = io::stdout().lock();
+    match format {
+        OutputFormat::Text => print_text(&mut out, &hits)?,
+        OutputFormat::Json => writeln!(out, "{}", serde_json::to_string_pretty(&hits)?)?,
+        OutputFormat::Csv => print_csv(&mut out, &hits)?,
+    }
+
+    Ok(if hits.is_empty() { EXIT_NO_MATCH } else { EXIT_SUCCESS })
+}
+
+/// Build a search hit with the match offsets for a verse
+fn to_hit<'a>(verse: &'a Verse, query: &SearchQuery) -> SearchHit<'a> {
+    let offsets = match query {
+        SearchQuery::Text(text) => match_offsets(&verse.text, text),
+        SearchQuery::Strongs(numbers) => strongs_offsets(verse, numbers),
+    };
+
+    SearchHit {
+        reference: verse.reference.to_string(),
+        book: &verse.reference.book,
+        chapter: verse.reference.chapter,
+        verse: verse.reference.verse,
+        text: &verse.text,
+        matches: offsets
+            .into_iter()
+            .map(|(start, end)| MatchOffset { start, end })
+            .collect(),
+    }
+}
+
+/// Print one "reference  text" line per hit
+fn print_text(out: &mut impl Write, hits: &[SearchHit]) -> io::Result<()> {
+    for hit in hits {
+        writeln!(out, "{}  {}", hit.reference, hit.text)?;
+    }
+    Ok(())
+}
+
+/// Print one CSV row per hit; offsets are "start-end" pairs joined by ';'
+fn print_csv(out: &mut impl Write, hits: &[SearchHit]) -> io::Result<()> {
+    writeln!(out, "book,chapter,verse,matches,text")?;
+    for hit in hits {
+        let matches = hit
+            .matches
+            .iter()
+            .map(|m| format!("{}-{}", m.start, m.end))
+            .collect::<Vec<_>>()
+            .join(";");
+
+        writeln!(
+            out,
+            "{},{},{},{},{}",
+            hit.book,
+            hit.chapter,
+            hit.verse,
+            matches,
+            csv_field(hit.text)
+        )?;
+    }
+    Ok(())
+}
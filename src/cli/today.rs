@@ -0,0 +1,52 @@
+use super::passage;
+use super::{OutputFormat, EXIT_SUCCESS};
+use crate::bible::daily::{load_verse_of_the_day, DailyVerseSource};
+use crate::bible::loader::BibleLoader;
+use crate::bible::Passage;
+use crate::config::Settings;
+use anyhow::{Context, Result};
+use chrono::{Local, NaiveDate};
+use clap::Args;
+use std::io::{self, Write};
+
+/// Arguments for `biblios today`
+#[derive(Debug, Args)]
+pub struct TodayArgs {
+    /// Show the verse for another day (YYYY-MM-DD) instead of today
+    #[arg(long)]
+    pub date: Option<NaiveDate>,
+
+    /// Where to pick the verse from (defaults to the configured source)
+    #[arg(long, value_enum)]
+    pub source: Option<DailyVerseSource>,
+}
+
+/// Print the verse of the day
+pub fn run(
+    loader: &BibleLoader,
+    translation: &str,
+    args: &TodayArgs,
+    format: OutputFormat,
+) -> Result<i32> {
+    let date = args.date.unwrap_or_else(|| Local::now().date_naive());
+    let source = match args.source {
+        Some(source) => source,
+        None => Settings::load()?.daily_verse,
+    };
+
+    let verse = load_verse_of_the_day(loader, source, date)?
+        .with_context(|| format!("No verse of the day found in {}", translation))?;
+
+    match format {
+        // One line, so it fits in a shell MOTD or prompt
+        OutputFormat::Text => {
+            writeln!(io::stdout().lock(), "{} — {} ({})", verse.text, verse.reference, translation)?
+        }
+        _ => {
+            let passage = Passage::from(&verse.reference);
+            passage::print(&passage, translation, std::slice::from_ref(&verse), format)?;
+        }
+    }
+
+    Ok(EXIT_SUCCESS)
+}
@@ -1,33 +1,81 @@
-use crate::bible::VerseReference;
+use crate::bible::{Passage, VerseReference};
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
 
 /// Represents a bookmark with optional notes
+///
+/// A bookmark covers `reference` through `end` when `end` is set, so a
+/// passage like Romans 8:28-39 is a single bookmark. Files written before
+/// ranges existed have no `end` and load as single-verse bookmarks.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Bookmark {
     pub reference: VerseReference,
+
+    /// Last verse of a range bookmark, in the same book as `reference`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<VerseReference>,
+
     pub note: Option<String>,
     pub created_at: String, // ISO 8601 timestamp
+
+    /// Named set this bookmark belongs to, e.g. "Memory verses"
+    #[serde(default)]
+    pub collection: Option<String>,
+
+    /// Free-form labels, e.g. "grace", "advent"
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 impl Bookmark {
     pub fn new(reference: VerseReference) -> Self {
         Self {
             reference,
+            end: None,
             note: None,
             created_at: chrono::Utc::now().to_rfc3339(),
+            collection: None,
+            tags: Vec::new(),
         }
     }
 
     pub fn with_note(reference: VerseReference, note: String) -> Self {
         Self {
             reference,
+            end: None,
             note: Some(note),
             created_at: chrono::Utc::now().to_rfc3339(),
+            collection: None,
+            tags: Vec::new(),
         }
     }
+
+    /// Bookmark the verses from `start` to `end`, in either order
+    pub fn range(start: VerseReference, end: VerseReference) -> Self {
+        let (start, end) = if (end.chapter, end.verse) < (start.chapter, start.verse) {
+            (end, start)
+        } else {
+            (start, end)
+        };
+        let end = (end != start).then_some(end);
+
+        Self {
+            end,
+            ..Self::new(start)
+        }
+    }
+
+    /// The passage this bookmark covers
+    pub fn passage(&self) -> Passage {
+        Passage::between(&self.reference, self.end.as_ref().unwrap_or(&self.reference))
+    }
+
+    /// Check whether a verse falls inside this bookmark
+    pub fn covers(&self, reference: &VerseReference) -> bool {
+        &self.reference == reference || self.passage().contains(reference)
+    }
 }
 
 /// Manages bookmarks
@@ -79,9 +127,72 @@ impl BookmarkManager {
         len_before != self.bookmarks.len()
     }
 
-    /// Check if a verse is bookmarked
+    /// Remove a bookmark, returning it with its position so it can be restored
+    pub fn take(&mut self, reference: &VerseReference) -> Option<(usize, Bookmark)> {
+        let index = self.bookmarks.iter().position(|b| &b.reference == reference)?;
+        Some((index, self.bookmarks.remove(index)))
+    }
+
+    /// Put back a bookmark removed with `take`
+    pub fn restore(&mut self, index: usize, bookmark: Bookmark) {
+        self.bookmarks.retain(|b| b.reference != bookmark.reference);
+        let index = index.min(self.bookmarks.len());
+        self.bookmarks.insert(index, bookmark);
+    }
+
+    /// Get the bookmark for a verse
+    pub fn get(&self, reference: &VerseReference) -> Option<&Bookmark> {
+        self.bookmarks.iter().find(|b| &b.reference == reference)
+    }
+
+    /// Set or clear a bookmark's note; blank text clears it
+    pub fn set_note(&mut self, reference: &VerseReference, note: String) {
+        if let Some(bookmark) = self.bookmarks.iter_mut().find(|b| &b.reference == reference) {
+            let note = note.trim_end().to_string();
+            bookmark.note = if note.trim().is_empty() { None } else { Some(note) };
+        }
+    }
+
+    /// Set a bookmark's collection and tags
+    pub fn set_labels(&mut self, reference: &VerseReference, collection: Option<String>, tags: Vec<String>) {
+        if let Some(bookmark) = self.bookmarks.iter_mut().find(|b| &b.reference == reference) {
+            bookmark.collection = collection;
+            bookmark.tags = tags;
+        }
+    }
+
+    /// Names of all collections in use, sorted, with case variants listed once
+    pub fn collections(&self) -> Vec<String> {
+        let mut collections: Vec<String> = self
+            .bookmarks
+            .iter()
+            .filter_map(|b| b.collection.clone())
+            .collect();
+        collections.sort_by_key(|name| name.to_lowercase());
+        collections.dedup_by_key(|name| name.to_lowercase());
+        collections
+    }
+
+    /// All tags in use, sorted, with case variants listed once
+    pub fn tags(&self) -> Vec<String> {
+        let mut tags: Vec<String> = self
+            .bookmarks
+            .iter()
+            .flat_map(|b| b.tags.iter().cloned())
+            .collect();
+        tags.sort_by_key(|tag| tag.to_lowercase());
+        tags.dedup_by_key(|tag| tag.to_lowercase());
+        tags
+    }
+
+    /// Check if a verse is bookmarked, directly or inside a range
     pub fn is_bookmarked(&self, reference: &VerseReference) -> bool {
-        self.bookmarks.iter().any(|b| &b.reference == reference)
+        self.covering(reference).is_some()
+    }
+
+    /// The bookmark covering a verse, if any
+    pub fn covering(&self, reference: &VerseReference) -> Option<&Bookmark> {
+        self.bookmarks.iter().find(|b| b.covers(reference))
     }
 
     /// Get all bookmarks
@@ -95,3 +206,60 @@ impl BookmarkManager {
         Ok(config_dir.join("bookmarks.toml"))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range_bookmarks_and_migration() {
+        // A file written before ranges existed still loads, as a single verse
+        let old = r#"
+[[bookmarks]]
+note = "Loved"
+created_at = "2024-01-01T00:00:00+00:00"
+
+[bookmarks.reference]
+book = "John"
+chapter = 3
+verse = 16
+"#;
+        let mut manager: BookmarkManager = toml::from_str(old).unwrap();
+        let john = VerseReference::new("John", 3, 16);
+        assert!(manager.is_bookmarked(&john));
+        assert!(!manager.is_bookmarked(&VerseReference::new("John", 3, 17)));
+        assert_eq!(manager.get(&john).unwrap().note.as_deref(), Some("Loved"));
+
+        // Ranges are normalised and cover every verse
+        let range = Bookmark::range(VerseReference::new("Rom", 8, 39), VerseReference::new("Rom", 8, 28));
+        assert_eq!(range.passage().to_string(), "Rom 8:28-39");
+        manager.add(range);
+        assert!(manager.is_bookmarked(&VerseReference::new("Rom", 8, 30)));
+        assert!(!manager.is_bookmarked(&VerseReference::new("Rom", 9, 1)));
+        assert_eq!(
+            manager.covering(&VerseReference::new("Rom", 8, 39)).unwrap().reference,
+            VerseReference::new("Rom", 8, 28)
+        );
+
+        // Round trip keeps both kinds
+        let saved = toml::to_string_pretty(&manager).unwrap();
+        let reloaded: BookmarkManager = toml::from_str(&saved).unwrap();
+        assert_eq!(reloaded.all().len(), 2);
+        assert!(reloaded.all()[0].end.is_none());
+        assert_eq!(reloaded.all()[1].end, Some(VerseReference::new("Rom", 8, 39)));
+    }
+
+    #[test]
+    fn test_labels_ignore_case() {
+        let mut manager = BookmarkManager::default();
+        for (verse, label) in [(1, "Work"), (2, "grace"), (3, "work"), (4, "Work")] {
+            let mut bookmark = Bookmark::new(VerseReference::new("Ps", 23, verse));
+            bookmark.collection = Some(label.to_string());
+            bookmark.tags = vec![label.to_string(), "Grace".to_string()];
+            manager.add(bookmark);
+        }
+
+        assert_eq!(manager.collections(), vec!["grace", "Work"]);
+        assert_eq!(manager.tags(), vec!["Grace", "Work"]);
+    }
+}
@@ -0,0 +1,193 @@
+use crate::bible::{Passage, VerseReference};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// Named highlighter colors; each theme supplies its own tint for each
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HighlightColor {
+    Yellow,
+    Green,
+    Blue,
+    Pink,
+    Orange,
+}
+
+impl HighlightColor {
+    /// All colors, in picker order
+    pub const ALL: [HighlightColor; 5] = [
+        HighlightColor::Yellow,
+        HighlightColor::Green,
+        HighlightColor::Blue,
+        HighlightColor::Pink,
+        HighlightColor::Orange,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            HighlightColor::Yellow => "Yellow",
+            HighlightColor::Green => "Green",
+            HighlightColor::Blue => "Blue",
+            HighlightColor::Pink => "Pink",
+            HighlightColor::Orange => "Orange",
+        }
+    }
+
+    /// Position in `ALL`, also the index into a theme's palette
+    pub fn index(self) -> usize {
+        Self::ALL.iter().position(|c| *c == self).unwrap_or(0)
+    }
+
+    /// The color picked with digit key `n` (1-based)
+    pub fn from_digit(n: u32) -> Option<Self> {
+        Self::ALL.get((n as usize).checked_sub(1)?).copied()
+    }
+}
+
+/// A highlighted verse or range of verses
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Highlight {
+    pub start: VerseReference,
+
+    /// Last verse of a range, in the same book as `start`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<VerseReference>,
+
+    pub color: HighlightColor,
+    pub created_at: String, // ISO 8601 timestamp
+}
+
+impl Highlight {
+    /// The passage this highlight covers
+    pub fn passage(&self) -> Passage {
+        Passage::between(&self.start, self.end.as_ref().unwrap_or(&self.start))
+    }
+
+    /// Check whether a verse falls inside this highlight
+    pub fn covers(&self, reference: &VerseReference) -> bool {
+        &self.start == reference || self.passage().contains(reference)
+    }
+}
+
+/// Manages verse highlights, persisted to `highlights.toml`
+///
+/// Later highlights are painted over earlier ones where they overlap.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct HighlightStore {
+    #[serde(default)]
+    highlights: Vec<Highlight>,
+}
+
+impl HighlightStore {
+    /// Load highlights from file or create empty
+    pub fn load() -> Result<Self> {
+        let path = Self::highlights_path()?;
+
+        if path.exists() {
+            let content = fs::read_to_string(&path)
+                .context("Failed to read highlights file")?;
+
+            toml::from_str(&content)
+                .context("Failed to parse highlights TOML")
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    /// Save highlights to file
+    pub fn save(&self) -> Result<()> {
+        let path = Self::highlights_path()?;
+
+        let content = toml::to_string_pretty(self)
+            .context("Failed to serialize highlights")?;
+
+        fs::write(&path, content)
+            .context("Failed to write highlights file")?;
+
+        Ok(())
+    }
+
+    /// Highlight the verses from `start` to `end`, in either order
+    ///
+    /// Highlights lying entirely inside the new one are replaced.
+    pub fn add(&mut self, start: VerseReference, end: VerseReference, color: HighlightColor) {
+        let (start, end) = if (end.chapter, end.verse) < (start.chapter, start.verse) {
+            (end, start)
+        } else {
+            (start, end)
+        };
+
+        let highlight = Highlight {
+            end: (end != start).then_some(end),
+            start,
+            color,
+            created_at: chrono::Utc::now().to_rfc3339(),
+        };
+
+        let passage = highlight.passage();
+        self.highlights.retain(|h| {
+            let last = h.end.as_ref().unwrap_or(&h.start);
+            !(passage.contains(&h.start) && passage.contains(last))
+        });
+        self.highlights.push(highlight);
+    }
+
+    /// Remove every highlight covering a verse
+    pub fn remove(&mut self, reference: &VerseReference) -> bool {
+        let len_before = self.highlights.len();
+        self.highlights.retain(|h| !h.covers(reference));
+        len_before != self.highlights.len()
+    }
+
+    /// The color a verse is painted, if any
+    pub fn color_of(&self, reference: &VerseReference) -> Option<HighlightColor> {
+        self.highlights
+            .iter()
+            .rev()
+            .find(|h| h.covers(reference))
+            .map(|h| h.color)
+    }
+
+    /// Get all highlights
+    pub fn all(&self) -> &[Highlight] {
+        &self.highlights
+    }
+
+    /// Get the highlights file path
+    fn highlights_path() -> Result<PathBuf> {
+        let config_dir = super::config_dir()?;
+        Ok(config_dir.join("highlights.toml"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_highlight_ranges_and_overlap() {
+        let mut store = HighlightStore::default();
+        let verse = |v| VerseReference::new("Rom", 8, v);
+
+        store.add(verse(30), verse(30), HighlightColor::Green);
+        store.add(verse(35), verse(28), HighlightColor::Yellow);
+        // The single-verse highlight inside the range is replaced
+        assert_eq!(store.all().len(), 1);
+        assert_eq!(store.all()[0].passage().to_string(), "Rom 8:28-35");
+        assert_eq!(store.color_of(&verse(30)), Some(HighlightColor::Yellow));
+
+        // A later, smaller highlight paints over the range
+        store.add(verse(31), verse(31), HighlightColor::Pink);
+        assert_eq!(store.color_of(&verse(31)), Some(HighlightColor::Pink));
+        assert_eq!(store.color_of(&verse(36)), None);
+
+        assert!(store.remove(&verse(31)));
+        assert!(store.all().is_empty());
+
+        assert_eq!(HighlightColor::from_digit(2), Some(HighlightColor::Green));
+        assert_eq!(HighlightColor::from_digit(0), None);
+        assert_eq!(HighlightColor::Blue.index(), 2);
+    }
+}
@@ -0,0 +1,260 @@
+use crate::bible::{get_chapter_count, BOOK_ORDER};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::PathBuf;
+
+/// A chapter opened in the Reader
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChapterVisit {
+    pub book: String,
+    pub chapter: u32,
+}
+
+/// One run of the application
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadingSession {
+    /// When the session started
+    pub started: DateTime<Local>,
+
+    /// Active reading time in seconds, excluding idle gaps
+    #[serde(default)]
+    pub seconds: u64,
+
+    /// Chapters visited, in order
+    #[serde(default)]
+    pub chapters: Vec<ChapterVisit>,
+}
+
+/// Persistent log of reading sessions
+///
+/// Persisted to `history.toml` next to the reading state.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReadingHistory {
+    #[serde(default)]
+    pub sessions: Vec<ReadingSession>,
+}
+
+/// Summary statistics derived from the reading history
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadingStats {
+    /// Distinct chapters read across the whole Bible
+    pub chapters_read: usize,
+
+    /// Chapters in the whole Bible
+    pub chapters_total: usize,
+
+    /// Consecutive reading days ending today (or yesterday)
+    pub current_streak: usize,
+
+    /// Longest run of consecutive reading days
+    pub longest_streak: usize,
+
+    /// Average reading time per reading day, in seconds
+    pub average_seconds: u64,
+
+    /// Number of days with any reading
+    pub reading_days: usize,
+}
+
+impl ReadingHistory {
+    /// Load history from file or create empty
+    pub fn load() -> Result<Self> {
+        let path = Self::history_path()?;
+
+        if path.exists() {
+            let content = fs::read_to_string(&path)
+                .context("Failed to read history file")?;
+
+            toml::from_str(&content)
+                .context("Failed to parse history TOML")
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    /// Save history to file, dropping sessions where nothing was read
+    pub fn save(&self) -> Result<()> {
+        let path = Self::history_path()?;
+
+        let mut history_to_save = self.clone();
+        history_to_save
+            .sessions
+            .retain(|session| session.seconds > 0 || !session.chapters.is_empty());
+
+        let content = toml::to_string_pretty(&history_to_save)
+            .context("Failed to serialize history")?;
+
+        fs::write(&path, content)
+            .context("Failed to write history file")?;
+
+        Ok(())
+    }
+
+    /// Start a new session
+    pub fn begin_session(&mut self, started: DateTime<Local>) {
+        self.sessions.push(ReadingSession {
+            started,
+            seconds: 0,
+            chapters: Vec::new(),
+        });
+    }
+
+    /// Log a chapter visit in the current session, ignoring repeats of the last visit
+    pub fn record_chapter(&mut self, book: &str, chapter: u32) {
+        let Some(session) = self.sessions.last_mut() else {
+            return;
+        };
+
+        let visit = ChapterVisit {
+            book: book.to_string(),
+            chapter,
+        };
+        if session.chapters.last() != Some(&visit) {
+            session.chapters.push(visit);
+        }
+    }
+
+    /// Add active reading time to the current session
+    pub fn add_time(&mut self, seconds: u64) {
+        if let Some(session) = self.sessions.last_mut() {
+            session.seconds += seconds;
+        }
+    }
+
+    /// Distinct chapters visited, as (book, chapter)
+    pub fn chapters_read(&self) -> HashSet<(&str, u32)> {
+        self.sessions
+            .iter()
+            .flat_map(|session| session.chapters.iter())
+            .map(|visit| (visit.book.as_str(), visit.chapter))
+            .collect()
+    }
+
+    /// Chapters read and total chapters for each book, in canonical order
+    pub fn book_coverage(&self) -> Vec<(&'static str, usize, usize)> {
+        let read = self.chapters_read();
+
+        BOOK_ORDER
+            .iter()
+            .map(|(book, _, _, count)| {
+                let done = (1..=*count).filter(|chapter| read.contains(&(*book, *chapter))).count();
+                (*book, done, *count as usize)
+            })
+            .collect()
+    }
+
+    /// Reading time in seconds for every day with any reading
+    pub fn daily_seconds(&self) -> BTreeMap<NaiveDate, u64> {
+        let mut days = BTreeMap::new();
+        for session in &self.sessions {
+            if session.seconds == 0 && session.chapters.is_empty() {
+                continue;
+            }
+            *days.entry(session.started.date_naive()).or_insert(0) += session.seconds;
+        }
+        days
+    }
+
+    /// Compute summary statistics as of `today`
+    pub fn stats(&self, today: NaiveDate) -> ReadingStats {
+        let chapters_read = self
+            .chapters_read()
+            .into_iter()
+            .filter(|(book, chapter)| *chapter >= 1 && *chapter <= get_chapter_count(book))
+            .count();
+        let chapters_total = BOOK_ORDER.iter().map(|(_, _, _, count)| *count as usize).sum();
+
+        let days = self.daily_seconds();
+        let total_seconds: u64 = days.values().sum();
+        let average_seconds = if days.is_empty() {
+            0
+        } else {
+            total_seconds / days.len() as u64
+        };
+
+        // Walk the reading days in order, tracking runs of consecutive dates
+        let mut longest_streak = 0;
+        let mut run = 0;
+        let mut previous: Option<NaiveDate> = None;
+        for date in days.keys() {
+            run = match previous {
+                Some(prev) if prev.succ_opt() == Some(*date) => run + 1,
+                _ => 1,
+            };
+            longest_streak = longest_streak.max(run);
+            previous = Some(*date);
+        }
+
+        // The current streak is still alive if the last reading was today or yesterday
+        let current_streak = match previous {
+            Some(last) if last == today || last.succ_opt() == Some(today) => run,
+            _ => 0,
+        };
+
+        ReadingStats {
+            chapters_read,
+            chapters_total,
+            current_streak,
+            longest_streak,
+            average_seconds,
+            reading_days: days.len(),
+        }
+    }
+
+    /// Get the history file path
+    fn history_path() -> Result<PathBuf> {
+        let config_dir = super::config_dir()?;
+        Ok(config_dir.join("history.toml"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn session_on(history: &mut ReadingHistory, day: u32, chapters: &[(&str, u32)]) {
+        let started = Local.with_ymd_and_hms(2024, 3, day, 8, 0, 0).unwrap();
+        history.begin_session(started);
+        for (book, chapter) in chapters {
+            history.record_chapter(book, *chapter);
+        }
+        history.add_time(600);
+    }
+
+    #[test]
+    fn test_coverage_and_streaks() {
+        let mut history = ReadingHistory::default();
+        session_on(&mut history, 1, &[("Ruth", 1), ("Ruth", 2)]);
+        session_on(&mut history, 2, &[("Ruth", 2), ("Ruth", 2), ("Ruth", 3)]);
+        session_on(&mut history, 3, &[("Ruth", 4)]);
+        session_on(&mut history, 6, &[("John", 1)]);
+        session_on(&mut history, 7, &[("John", 2)]);
+
+        // Repeated visits within a session are logged once
+        assert_eq!(history.sessions[1].chapters.len(), 2);
+
+        let ruth = history
+            .book_coverage()
+            .into_iter()
+            .find(|(book, _, _)| *book == "Ruth")
+            .unwrap();
+        assert_eq!(ruth, ("Ruth", 4, 4));
+
+        let today = NaiveDate::from_ymd_opt(2024, 3, 8).unwrap();
+        let stats = history.stats(today);
+        assert_eq!(stats.chapters_read, 6);
+        assert_eq!(stats.chapters_total, 1189);
+        assert_eq!(stats.longest_streak, 3);
+        assert_eq!(stats.current_streak, 2);
+        assert_eq!(stats.average_seconds, 600);
+        assert_eq!(stats.reading_days, 5);
+
+        // A missed day breaks the current streak
+        let later = NaiveDate::from_ymd_opt(2024, 3, 9).unwrap();
+        assert_eq!(history.stats(later).current_streak, 0);
+    }
+}
@@ -1,10 +1,22 @@
 pub mod settings;
 pub mod bookmarks;
 pub mod state;
+pub mod plans;
+pub mod history;
+pub mod read_chapters;
+pub mod notes;
+pub mod highlights;
+pub mod word_marks;
 
 pub use settings::Settings;
 pub use bookmarks::{Bookmark, BookmarkManager};
 pub use state::ReadingState;
+pub use plans::PlanProgress;
+pub use history::ReadingHistory;
+pub use read_chapters::ReadChapters;
+pub use notes::NoteStore;
+pub use highlights::{HighlightColor, HighlightStore};
+pub use word_marks::WordMarkStore;
 
 use anyhow::{Context, Result};
 use std::path::PathBuf;
@@ -36,3 +48,25 @@ pub fn data_dir() -> Result<PathBuf> {
 
     Ok(dir)
 }
+
+/// Get the database path for a translation (e.g. "KJV" -> translations/kjv.sqlite)
+pub fn translation_path(translation: &str) -> Result<PathBuf> {
+    Ok(data_dir()?
+        .join("translations")
+        .join(format!("{}.sqlite", translation.to_lowercase())))
+}
+
+/// Get the cross-reference database path, shared by all translations
+pub fn crossrefs_path() -> Result<PathBuf> {
+    Ok(data_dir()?.join("crossrefs.sqlite"))
+}
+
+/// Get the Strong's lexicon database path, shared by all translations
+pub fn lexicon_path() -> Result<PathBuf> {
+    Ok(data_dir()?.join("lexicon.sqlite"))
+}
+
+/// Get the commentary and dictionary module database path, shared by all translations
+pub fn modules_path() -> Result<PathBuf> {
+    Ok(data_dir()?.join("modules.sqlite"))
+}
@@ -0,0 +1,93 @@
+use crate::bible::VerseReference;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// A study note attached to a verse
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Note {
+    pub reference: VerseReference,
+    pub text: String,
+    pub created_at: String, // ISO 8601 timestamp
+    pub updated_at: String, // ISO 8601 timestamp
+}
+
+/// Manages verse notes
+///
+/// Kept separate from bookmarks so removing a bookmark never loses a note.
+/// Persisted to `notes.toml`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct NoteStore {
+    #[serde(default)]
+    notes: Vec<Note>,
+}
+
+impl NoteStore {
+    /// Load notes from file or create empty
+    pub fn load() -> Result<Self> {
+        let path = Self::notes_path()?;
+
+        if path.exists() {
+            let content = fs::read_to_string(&path)
+                .context("Failed to read notes file")?;
+
+            toml::from_str(&content)
+                .context("Failed to parse notes TOML")
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    /// Save notes to file
+    pub fn save(&self) -> Result<()> {
+        let path = Self::notes_path()?;
+
+        let content = toml::to_string_pretty(self)
+            .context("Failed to serialize notes")?;
+
+        fs::write(&path, content)
+            .context("Failed to write notes file")?;
+
+        Ok(())
+    }
+
+    /// Get the note for a verse
+    pub fn get(&self, reference: &VerseReference) -> Option<&Note> {
+        self.notes.iter().find(|n| &n.reference == reference)
+    }
+
+    /// Check if a verse has a note
+    pub fn has_note(&self, reference: &VerseReference) -> bool {
+        self.get(reference).is_some()
+    }
+
+    /// Set the note for a verse; blank text removes the note
+    pub fn set(&mut self, reference: VerseReference, text: String) {
+        let text = text.trim_end().to_string();
+        if text.trim().is_empty() {
+            self.notes.retain(|n| n.reference != reference);
+            return;
+        }
+
+        let now = chrono::Utc::now().to_rfc3339();
+        match self.notes.iter_mut().find(|n| n.reference == reference) {
+            Some(note) => {
+                note.text = text;
+                note.updated_at = now;
+            }
+            None => self.notes.push(Note {
+                reference,
+                text,
+                created_at: now.clone(),
+                updated_at: now,
+            }),
+        }
+    }
+
+    /// Get the notes file path
+    fn notes_path() -> Result<PathBuf> {
+        let config_dir = super::config_dir()?;
+        Ok(config_dir.join("notes.toml"))
+    }
+}
@@ -0,0 +1,219 @@
+use crate::bible::plans::{builtin_plans, ReadingPlan};
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// A reading that has been completed: day number (1-based) and index within the day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompletedReading {
+    pub day: usize,
+    pub index: usize,
+}
+
+/// Progress through the active reading plan
+///
+/// Persisted to `plans.toml` next to the reading state.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PlanProgress {
+    /// ID of the plan being followed
+    pub plan_id: Option<String>,
+
+    /// Date the plan was started; day 1 is this date
+    pub start_date: Option<NaiveDate>,
+
+    /// Readings marked as done
+    #[serde(default)]
+    pub completed: Vec<CompletedReading>,
+}
+
+/// Catch-up status for the active plan
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlanStatus {
+    /// Today's day number in the plan (1-based, capped at the plan length)
+    pub current_day: usize,
+
+    /// Earlier days that still have unfinished readings
+    pub days_behind: usize,
+
+    /// Readings finished so far
+    pub completed: usize,
+
+    /// Readings in the whole plan
+    pub total: usize,
+}
+
+impl PlanProgress {
+    /// Load plan progress from file or create empty
+    pub fn load() -> Result<Self> {
+        let path = Self::progress_path()?;
+
+        if path.exists() {
+            let content = fs::read_to_string(&path)
+                .context("Failed to read plan progress file")?;
+
+            toml::from_str(&content)
+                .context("Failed to parse plan progress TOML")
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    /// Save plan progress to file
+    pub fn save(&self) -> Result<()> {
+        let path = Self::progress_path()?;
+
+        let content = toml::to_string_pretty(self)
+            .context("Failed to serialize plan progress")?;
+
+        fs::write(&path, content)
+            .context("Failed to write plan progress file")?;
+
+        Ok(())
+    }
+
+    /// Start following a plan from the given date, clearing old progress
+    pub fn start(&mut self, plan_id: &str, date: NaiveDate) {
+        self.plan_id = Some(plan_id.to_string());
+        self.start_date = Some(date);
+        self.completed.clear();
+    }
+
+    /// Check if a reading is done
+    pub fn is_completed(&self, day: usize, index: usize) -> bool {
+        self.completed.contains(&CompletedReading { day, index })
+    }
+
+    /// Mark a reading as done; returns false if it already was
+    pub fn complete(&mut self, day: usize, index: usize) -> bool {
+        if self.is_completed(day, index) {
+            return false;
+        }
+        self.completed.push(CompletedReading { day, index });
+        true
+    }
+
+    /// Today's day number in the plan (1-based), if a plan is active
+    pub fn day_for(&self, plan: &ReadingPlan, date: NaiveDate) -> Option<usize> {
+        let start = self.start_date?;
+        let elapsed = (date - start).num_days().max(0) as usize;
+        Some((elapsed + 1).min(plan.len()))
+    }
+
+    /// Readings due by `date` that aren't done yet, oldest first, as (day, index)
+    pub fn outstanding(&self, plan: &ReadingPlan, date: NaiveDate) -> Vec<(usize, usize)> {
+        let Some(current_day) = self.day_for(plan, date) else {
+            return Vec::new();
+        };
+
+        plan.days[..current_day]
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, readings)| (0..readings.len()).map(move |index| (idx + 1, index)))
+            .filter(|(day, index)| !self.is_completed(*day, *index))
+            .collect()
+    }
+
+    /// Catch-up status for the plan on `date`
+    pub fn status(&self, plan: &ReadingPlan, date: NaiveDate) -> Option<PlanStatus> {
+        let current_day = self.day_for(plan, date)?;
+
+        let mut behind: Vec<usize> = self
+            .outstanding(plan, date)
+            .into_iter()
+            .map(|(day, _)| day)
+            .filter(|day| *day < current_day)
+            .collect();
+        behind.dedup();
+
+        Some(PlanStatus {
+            current_day,
+            days_behind: behind.len(),
+            completed: self.completed.len(),
+            total: plan.reading_count(),
+        })
+    }
+
+    /// Get the plan progress file path
+    fn progress_path() -> Result<PathBuf> {
+        let config_dir = super::config_dir()?;
+        Ok(config_dir.join("plans.toml"))
+    }
+}
+
+/// Load built-in plans followed by user plans from `config_dir()/plans/`
+///
+/// User plans may be `.toml` or `.json`; files that fail to parse are skipped.
+pub fn load_plans() -> Result<Vec<ReadingPlan>> {
+    let mut plans = builtin_plans();
+
+    let dir = super::config_dir()?.join("plans");
+    if !dir.exists() {
+        return Ok(plans);
+    }
+
+    let mut paths: Vec<PathBuf> = fs::read_dir(&dir)
+        .context("Failed to read plans directory")?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .collect();
+    paths.sort();
+
+    for path in paths {
+        let Some(id) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+
+        let parsed: Option<ReadingPlan> = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => toml::from_str(&content).ok(),
+            Some("json") => serde_json::from_str(&content).ok(),
+            _ => None,
+        };
+
+        if let Some(mut plan) = parsed {
+            plan.id = id.to_string();
+            plans.push(plan);
+        }
+    }
+
+    Ok(plans)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn short_plan() -> ReadingPlan {
+        toml::from_str(r#"
+            name = "Short"
+            days = [["Matt 1"], ["Matt 2", "Ps 1"], ["Matt 3"]]
+        "#)
+        .unwrap()
+    }
+
+    #[test]
+    fn test_outstanding_and_catch_up_status() {
+        let plan = short_plan();
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let mut progress = PlanProgress::default();
+        progress.start("short", start);
+
+        let day3 = NaiveDate::from_ymd_opt(2024, 1, 3).unwrap();
+        assert_eq!(progress.outstanding(&plan, day3), vec![(1, 0), (2, 0), (2, 1), (3, 0)]);
+        assert_eq!(progress.status(&plan, day3).unwrap().days_behind, 2);
+
+        progress.complete(1, 0);
+        progress.complete(2, 1);
+        assert!(!progress.complete(2, 1));
+        let status = progress.status(&plan, day3).unwrap();
+        assert_eq!((status.current_day, status.days_behind), (3, 1));
+        assert_eq!((status.completed, status.total), (2, 4));
+
+        // Past the end of the plan, the last day stays current
+        let later = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
+        assert_eq!(progress.day_for(&plan, later), Some(3));
+    }
+}
@@ -0,0 +1,117 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// Chapters the user has finished reading
+///
+/// Chapters are marked automatically when the Reader reaches the last verse,
+/// or toggled by hand. Persisted to `read.toml`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReadChapters {
+    /// Finished chapter numbers by book, kept sorted
+    #[serde(default)]
+    pub books: BTreeMap<String, Vec<u32>>,
+}
+
+impl ReadChapters {
+    /// Load read chapters from file or create empty
+    pub fn load() -> Result<Self> {
+        let path = Self::read_path()?;
+
+        if path.exists() {
+            let content = fs::read_to_string(&path)
+                .context("Failed to read read-chapters file")?;
+
+            toml::from_str(&content)
+                .context("Failed to parse read-chapters TOML")
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    /// Save read chapters to file
+    pub fn save(&self) -> Result<()> {
+        let path = Self::read_path()?;
+
+        let content = toml::to_string_pretty(self)
+            .context("Failed to serialize read chapters")?;
+
+        fs::write(&path, content)
+            .context("Failed to write read-chapters file")?;
+
+        Ok(())
+    }
+
+    /// Check if a chapter has been read
+    pub fn is_read(&self, book: &str, chapter: u32) -> bool {
+        self.chapters(book).binary_search(&chapter).is_ok()
+    }
+
+    /// Finished chapters of a book, in order
+    pub fn chapters(&self, book: &str) -> &[u32] {
+        self.books.get(book).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Mark a chapter as read; returns false if it already was
+    pub fn mark_read(&mut self, book: &str, chapter: u32) -> bool {
+        let chapters = self.books.entry(book.to_string()).or_default();
+        match chapters.binary_search(&chapter) {
+            Ok(_) => false,
+            Err(pos) => {
+                chapters.insert(pos, chapter);
+                true
+            }
+        }
+    }
+
+    /// Flip a chapter's read state; returns the new state
+    pub fn toggle(&mut self, book: &str, chapter: u32) -> bool {
+        if self.mark_read(book, chapter) {
+            return true;
+        }
+
+        if let Some(chapters) = self.books.get_mut(book) {
+            chapters.retain(|c| *c != chapter);
+            if chapters.is_empty() {
+                self.books.remove(book);
+            }
+        }
+        false
+    }
+
+    /// Total number of chapters read across all books
+    pub fn total(&self) -> usize {
+        self.books.values().map(Vec::len).sum()
+    }
+
+    /// Get the read-chapters file path
+    fn read_path() -> Result<PathBuf> {
+        let config_dir = super::config_dir()?;
+        Ok(config_dir.join("read.toml"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mark_and_toggle() {
+        let mut read = ReadChapters::default();
+        assert!(read.mark_read("John", 3));
+        assert!(read.mark_read("John", 1));
+        assert!(!read.mark_read("John", 3));
+        assert_eq!(read.chapters("John"), &[1, 3]);
+
+        assert!(!read.toggle("John", 1));
+        assert!(read.toggle("Ruth", 2));
+        assert!(read.is_read("Ruth", 2));
+        assert!(!read.is_read("John", 1));
+        assert_eq!(read.total(), 2);
+
+        read.toggle("Ruth", 2);
+        assert!(!read.books.contains_key("Ruth"));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::bible::daily::DailyVerseSource;
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::fs;
@@ -30,6 +31,18 @@ pub struct Settings {
 
     /// Search result limit
     pub search_limit: usize,
+
+    /// Source of the verse of the day
+    pub daily_verse: DailyVerseSource,
+
+    /// Show Strong's numbers after words in tagged translations
+    pub show_strongs: bool,
+
+    /// One verse per line, or verses flowing into paragraphs
+    pub layout: ReadingLayout,
+
+    /// Id of the module shown in the Reader's companion pane
+    pub reference_module: Option<String>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -38,6 +51,15 @@ pub enum InputMode {
     Vim,
 }
 
+/// How the Reader lays out a chapter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReadingLayout {
+    /// One verse per line
+    Verses,
+    /// Verses flow into paragraphs with inline verse numbers
+    Paragraphs,
+}
+
 impl Default for Settings {
     fn default() -> Self {
         Self {
@@ -49,6 +71,10 @@ impl Default for Settings {
             verse_spacing: true,
             verses_per_page: 20,
             search_limit: 100,
+            daily_verse: DailyVerseSource::Curated,
+            show_strongs: false,
+            layout: ReadingLayout::Verses,
+            reference_module: None,
         }
     }
 }
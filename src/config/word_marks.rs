@@ -0,0 +1,206 @@
+use crate::bible::VerseReference;
+use crate::config::HighlightColor;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// A style applied to words inside a verse
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WordStyle {
+    Underline,
+    Bold,
+    Color(HighlightColor),
+}
+
+/// Styling on a span of characters within one verse of one translation
+///
+/// Offsets count chars, not bytes: `start` is inclusive, `end` exclusive.
+/// They are tied to the translation because the same verse has different
+/// wording, and so different offsets, in each.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WordMark {
+    pub reference: VerseReference,
+    pub translation: String,
+    pub start: usize,
+    pub end: usize,
+
+    #[serde(default)]
+    pub underline: bool,
+    #[serde(default)]
+    pub bold: bool,
+    #[serde(default)]
+    pub color: Option<HighlightColor>,
+}
+
+impl WordMark {
+    fn is_empty(&self) -> bool {
+        !self.underline && !self.bold && self.color.is_none()
+    }
+
+    fn is_for(&self, reference: &VerseReference, translation: &str) -> bool {
+        &self.reference == reference && self.translation == translation
+    }
+}
+
+/// Manages word-level marks, persisted to `word_marks.toml`
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct WordMarkStore {
+    #[serde(default)]
+    marks: Vec<WordMark>,
+}
+
+impl WordMarkStore {
+    /// Load word marks from file or create empty
+    pub fn load() -> Result<Self> {
+        let path = Self::marks_path()?;
+
+        if path.exists() {
+            let content = fs::read_to_string(&path)
+                .context("Failed to read word marks file")?;
+
+            toml::from_str(&content)
+                .context("Failed to parse word marks TOML")
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    /// Save word marks to file
+    pub fn save(&self) -> Result<()> {
+        let path = Self::marks_path()?;
+
+        let content = toml::to_string_pretty(self)
+            .context("Failed to serialize word marks")?;
+
+        fs::write(&path, content)
+            .context("Failed to write word marks file")?;
+
+        Ok(())
+    }
+
+    /// Marks on a verse in a translation, oldest first
+    pub fn for_verse(&self, reference: &VerseReference, translation: &str) -> Vec<&WordMark> {
+        self.marks
+            .iter()
+            .filter(|m| m.is_for(reference, translation))
+            .collect()
+    }
+
+    /// Toggle a style on the chars `start..end`
+    ///
+    /// Styles toggle per span, so applying the same style to the same words
+    /// again removes it. Choosing the color a span already has clears it.
+    pub fn toggle(
+        &mut self,
+        reference: &VerseReference,
+        translation: &str,
+        start: usize,
+        end: usize,
+        style: WordStyle,
+    ) {
+        let index = match self
+            .marks
+            .iter()
+            .position(|m| m.is_for(reference, translation) && m.start == start && m.end == end)
+        {
+            Some(index) => index,
+            None => {
+                self.marks.push(WordMark {
+                    reference: reference.clone(),
+                    translation: translation.to_string(),
+                    start,
+                    end,
+                    underline: false,
+                    bold: false,
+                    color: None,
+                });
+                self.marks.len() - 1
+            }
+        };
+
+        let mark = &mut self.marks[index];
+        match style {
+            WordStyle::Underline => mark.underline = !mark.underline,
+            WordStyle::Bold => mark.bold = !mark.bold,
+            WordStyle::Color(color) => {
+                mark.color = if mark.color == Some(color) { None } else { Some(color) };
+            }
+        }
+
+        if mark.is_empty() {
+            self.marks.remove(index);
+        }
+    }
+
+    /// Remove every mark overlapping the chars `start..end`
+    pub fn clear(&mut self, reference: &VerseReference, translation: &str, start: usize, end: usize) -> bool {
+        let len_before = self.marks.len();
+        self.marks
+            .retain(|m| !(m.is_for(reference, translation) && m.start < end && start < m.end));
+        len_before != self.marks.len()
+    }
+
+    /// Get the word marks file path
+    fn marks_path() -> Result<PathBuf> {
+        let config_dir = super::config_dir()?;
+        Ok(config_dir.join("word_marks.toml"))
+    }
+}
+
+/// Char ranges of the words in a verse, without surrounding punctuation
+pub fn word_ranges(text: &str) -> Vec<(usize, usize)> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut words = Vec::new();
+    let mut start = None;
+
+    for (idx, c) in chars.iter().chain(std::iter::once(&' ')).enumerate() {
+        match (start, c.is_whitespace()) {
+            (None, false) => start = Some(idx),
+            (Some(begin), true) => {
+                // Trim punctuation like quotes and commas, keeping inner apostrophes
+                let word = &chars[begin..idx];
+                let lead = word.iter().take_while(|c| !c.is_alphanumeric()).count();
+                let trail = word.iter().rev().take_while(|c| !c.is_alphanumeric()).count();
+                if lead < word.len() {
+                    words.push((begin + lead, idx - trail));
+                }
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_ranges_and_toggling() {
+        let text = "“For God so loved the world, that he gave his only begotten Son,";
+        let words = word_ranges(text);
+        let word = |(start, end): (usize, usize)| text.chars().skip(start).take(end - start).collect::<String>();
+        assert_eq!(word(words[0]), "For");
+        assert_eq!(word(words[5]), "world");
+        assert_eq!(words.len(), 13);
+
+        let mut store = WordMarkStore::default();
+        let john = VerseReference::new("John", 3, 16);
+        store.toggle(&john, "KJV", 1, 8, WordStyle::Underline);
+        store.toggle(&john, "KJV", 1, 8, WordStyle::Color(HighlightColor::Green));
+        assert_eq!(store.for_verse(&john, "KJV").len(), 1);
+        assert!(store.for_verse(&john, "WEB").is_empty());
+
+        // Toggling both styles off removes the mark
+        store.toggle(&john, "KJV", 1, 8, WordStyle::Underline);
+        store.toggle(&john, "KJV", 1, 8, WordStyle::Color(HighlightColor::Green));
+        assert!(store.for_verse(&john, "KJV").is_empty());
+
+        store.toggle(&john, "KJV", 9, 11, WordStyle::Bold);
+        assert!(!store.clear(&john, "KJV", 0, 9));
+        assert!(store.clear(&john, "KJV", 10, 11));
+    }
+}
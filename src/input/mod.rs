@@ -20,11 +20,30 @@ pub enum Action {
     OpenSearch,
     SearchNext,
     SearchPrevious,
+    OpenConcordance,
 
     // Bookmarks
     ToggleBookmark,
+    MarkBookmarkRange,
     OpenBookmarks,
 
+    // Highlights
+    OpenHighlighter,
+    OpenHighlights,
+    EnterWordMode,
+
+    // Cross-references, footnotes, the outline, reference modules and analytics
+    ToggleCrossRefs,
+    OpenFootnotes,
+    ToggleOutline,
+    ToggleCompanion,
+    ToggleAnalytics,
+
+    // Notes
+    EditNote,
+    EditNoteExternal,
+    SaveNote,
+
     // Verse Selector
     OpenVerseSelector,
     SelectorUp,
@@ -32,6 +51,16 @@ pub enum Action {
     SelectorSelect,
     SelectorBack,
 
+    // Reading plans
+    OpenReadingPlan,
+
+    // Statistics
+    OpenStats,
+
+    // Read tracking
+    ToggleChapterRead,
+    OpenCoverage,
+
     // Settings
     OpenSettings,
 
@@ -105,6 +134,58 @@ pub fn process_selector_key_event(event: KeyEvent) -> Action {
     }
 }
 
+/// Process key event when the note editor is open - every printable key is text
+pub fn process_editor_key_event(event: KeyEvent) -> Action {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    match event.code {
+        // Save and quit shortcuts
+        KeyCode::Char('s') if event.modifiers.contains(KeyModifiers::CONTROL) => Action::SaveNote,
+        KeyCode::Char('c') if event.modifiers.contains(KeyModifiers::CONTROL) => Action::Quit,
+        KeyCode::Char(_) if event.modifiers.contains(KeyModifiers::CONTROL) => Action::None,
+
+        // Cursor movement
+        KeyCode::Up => Action::ScrollUp,
+        KeyCode::Down => Action::ScrollDown,
+        KeyCode::Left => Action::PreviousVerse,
+        KeyCode::Right => Action::NextVerse,
+        KeyCode::Home => Action::GoToTop,
+        KeyCode::End => Action::GoToBottom,
+
+        // Editing
+        KeyCode::Enter => Action::Enter,
+        KeyCode::Esc => Action::Escape,
+        KeyCode::Backspace => Action::Backspace,
+        KeyCode::Delete => Action::Delete,
+        KeyCode::Tab => Action::Char(' '),
+        KeyCode::Char(c) => Action::Char(c),
+
+        _ => Action::None,
+    }
+}
+
+/// Process key event when the word cursor is active - letters pick styles
+pub fn process_word_key_event(event: KeyEvent) -> Action {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    match event.code {
+        KeyCode::Char('c') if event.modifiers.contains(KeyModifiers::CONTROL) => Action::Quit,
+        KeyCode::Char(_) if event.modifiers.contains(KeyModifiers::CONTROL) => Action::None,
+
+        // Word movement
+        KeyCode::Left => Action::PreviousVerse,
+        KeyCode::Right => Action::NextVerse,
+        KeyCode::Home => Action::GoToTop,
+        KeyCode::End => Action::GoToBottom,
+
+        KeyCode::Enter => Action::Enter,
+        KeyCode::Esc => Action::Escape,
+        KeyCode::Char(c) => Action::Char(c),
+
+        _ => Action::None,
+    }
+}
+
 /// Process key event when modal (settings/help) is open
 pub fn process_modal_key_event(event: KeyEvent, allow_navigation: bool) -> Action {
     use crossterm::event::{KeyCode, KeyModifiers};
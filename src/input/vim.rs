@@ -18,13 +18,41 @@ pub fn handle_key(event: KeyEvent) -> Action {
 
         // Search
         KeyCode::Char('/') => Action::OpenSearch,
+        KeyCode::Char('W') => Action::OpenConcordance,
         KeyCode::Char('n') => Action::SearchNext,
         KeyCode::Char('N') => Action::SearchPrevious,
 
         // Bookmarks
         KeyCode::Char('m') => Action::ToggleBookmark,
+        KeyCode::Char('M') => Action::MarkBookmarkRange,
         KeyCode::Char('b') => Action::OpenBookmarks,
 
+        // Highlights
+        KeyCode::Char('H') => Action::OpenHighlighter,
+        KeyCode::Char('L') => Action::OpenHighlights,
+        KeyCode::Char('w') => Action::EnterWordMode,
+
+        // Cross-references, footnotes, the outline, reference modules and analytics
+        KeyCode::Char('x') => Action::ToggleCrossRefs,
+        KeyCode::Char('f') if !event.modifiers.contains(KeyModifiers::CONTROL) => Action::OpenFootnotes,
+        KeyCode::Char('o') => Action::ToggleOutline,
+        KeyCode::Char('K') => Action::ToggleCompanion,
+        KeyCode::Char('A') => Action::ToggleAnalytics,
+
+        // Notes
+        KeyCode::Char('e') => Action::EditNote,
+        KeyCode::Char('E') => Action::EditNoteExternal,
+
+        // Reading plans
+        KeyCode::Char('p') => Action::OpenReadingPlan,
+
+        // Statistics
+        KeyCode::Char('S') => Action::OpenStats,
+
+        // Read tracking
+        KeyCode::Char('r') => Action::ToggleChapterRead,
+        KeyCode::Char('C') => Action::OpenCoverage,
+
         // Settings
         KeyCode::Char('s') => Action::OpenSettings,
 
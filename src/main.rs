@@ -1,5 +1,6 @@
 mod app;
 mod bible;
+mod cli;
 mod config;
 mod input;
 mod ui;
@@ -7,6 +8,7 @@ mod utils;
 
 use anyhow::Result;
 use app::App;
+use clap::Parser;
 use crossterm::{
     event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
     execute,
@@ -17,27 +19,35 @@ use std::io;
 use std::time::Duration;
 
 fn main() -> Result<()> {
+    let cli = cli::Cli::parse();
+    if cli.is_non_interactive() {
+        let code = cli::run(cli).unwrap_or_else(|err| {
+            eprintln!("Error: {:#}", err);
+            cli::EXIT_ERROR
+        });
+        std::process::exit(code);
+    }
+
+    // The reader always opens the installed KJV
+    if cli.translation.is_some() {
+        eprintln!("Error: --translation only applies when printing a passage or running a command");
+        std::process::exit(cli::EXIT_ERROR);
+    }
+
     // Initialize app
     let db_path = get_or_create_sample_db()?;
-    let mut app = App::new()?.with_bible(&db_path)?;
+    let mut app = App::new()?.with_bible("KJV", &db_path)?;
 
     // Setup terminal
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    let backend = CrosstermBackend::new(stdout);
+    setup_terminal()?;
+    let backend = CrosstermBackend::new(io::stdout());
     let mut terminal = Terminal::new(backend)?;
 
     // Run the app
     let res = run_app(&mut terminal, &mut app);
 
     // Restore terminal
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
+    restore_terminal()?;
     terminal.show_cursor()?;
 
     if let Err(err) = res {
@@ -60,12 +70,33 @@ fn run_app<B: ratatui::backend::Backend>(
                 if matches!(
                     key.code,
                     KeyCode::Char('q') | KeyCode::Char('c')
-                ) && app.view_mode == app::ViewMode::Reader {
+                ) && app.view_mode == app::ViewMode::Reader
+                    && app.note_editor.is_none()
+                    && app.bookmark_prompt.is_none()
+                    && app.word_cursor.is_none()
+                    && !app.selector_open
+                    && !app.highlight_picker_open
+                {
                     break;
                 }
 
                 // Process key event
-                let action = if app.selector_open {
+                let action = if app.note_editor.is_some() {
+                    // Note editor takes all text input
+                    input::process_editor_key_event(key)
+                } else if app.lexicon_popup.is_some() {
+                    // Lexicon popup is read-only, only allow close
+                    input::process_modal_key_event(key, false)
+                } else if app.word_cursor.is_some() {
+                    // Word cursor keys mark words inside the current verse
+                    input::process_word_key_event(key)
+                } else if app.selector_open
+                    || app.view_mode == app::ViewMode::Search
+                    || app.view_mode == app::ViewMode::Concordance
+                    || app.bookmark_filter_active
+                    || app.bookmark_prompt.is_some()
+                    || app.highlight_picker_open
+                {
                     // When selector is open, handle text input specially
                     input::process_selector_key_event(key)
                 } else if app.theme_picker_open {
@@ -74,6 +105,12 @@ fn run_app<B: ratatui::backend::Backend>(
                 } else if app.settings_open {
                     // Settings modal allows navigation to change settings
                     input::process_modal_key_event(key, true)
+                } else if app.plan_open {
+                    // Reading plan panel allows navigation to pick readings
+                    input::process_modal_key_event(key, true)
+                } else if app.footnotes_open {
+                    // Footnotes popup is read-only, only allow close
+                    input::process_modal_key_event(key, false)
                 } else if app.help_open {
                     // Help modal is read-only, only allow close
                     input::process_modal_key_event(key, false)
@@ -83,6 +120,15 @@ fn run_app<B: ratatui::backend::Backend>(
 
                 app.handle_action(action)?;
 
+                // Hand the terminal to an external editor, then take it back
+                if let Some(reference) = app.external_edit.take() {
+                    restore_terminal()?;
+                    terminal.show_cursor()?;
+                    app.edit_note_externally(&reference);
+                    setup_terminal()?;
+                    terminal.clear()?;
+                }
+
                 if app.should_quit {
                     break;
                 }
@@ -93,11 +139,24 @@ fn run_app<B: ratatui::backend::Backend>(
     Ok(())
 }
 
+/// Enter raw mode and the alternate screen
+fn setup_terminal() -> Result<()> {
+    enable_raw_mode()?;
+    execute!(io::stdout(), EnterAlternateScreen, EnableMouseCapture)?;
+    Ok(())
+}
+
+/// Leave raw mode and the alternate screen
+fn restore_terminal() -> Result<()> {
+    disable_raw_mode()?;
+    execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture)?;
+    Ok(())
+}
+
 /// Get the KJV Bible database path
 fn get_or_create_sample_db() -> Result<String> {
     let data_dir = config::data_dir()?;
-    let translations_dir = data_dir.join("translations");
-    let kjv_path = translations_dir.join("kjv.sqlite");
+    let kjv_path = config::translation_path("KJV")?;
 
     // If KJV doesn't exist, check for it or create sample
     if !kjv_path.exists() {
@@ -0,0 +1,248 @@
+use crate::app::App;
+use crate::bible::{Passage, VerseReference};
+use crate::ui::icons;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Clear, Paragraph},
+    Frame,
+};
+
+/// Maximum suggestions listed under the input
+const MAX_SUGGESTIONS: usize = 6;
+
+/// Prompt for a bookmark's collection and tags
+///
+/// The input is a collection name followed by `#tags`, e.g.
+/// `Sermon series #grace #romans`.
+#[derive(Debug, Clone)]
+pub struct BookmarkPrompt {
+    pub reference: VerseReference,
+    /// Last verse when bookmarking a range
+    pub end: Option<VerseReference>,
+    pub input: String,
+    /// True when relabelling an existing bookmark rather than adding one
+    pub editing: bool,
+}
+
+impl BookmarkPrompt {
+    /// Start a prompt, prefilled with existing labels
+    pub fn new(reference: VerseReference, collection: Option<&str>, tags: &[String], editing: bool) -> Self {
+        Self {
+            reference,
+            end: None,
+            input: format_labels(collection, tags),
+            editing,
+        }
+    }
+
+    /// The bookmarked verse or range, for display
+    pub fn label(&self) -> String {
+        Passage::between(&self.reference, self.end.as_ref().unwrap_or(&self.reference)).to_string()
+    }
+
+    /// Collection and tags from the input
+    pub fn labels(&self) -> (Option<String>, Vec<String>) {
+        parse_labels(&self.input)
+    }
+
+    /// Replace the word being typed with the first suggestion
+    pub fn complete(&mut self, collections: &[String], tags: &[String]) {
+        if let Some(suggestion) = suggestions(&self.input, collections, tags).into_iter().next() {
+            self.input = apply_suggestion(&self.input, &suggestion);
+        }
+    }
+}
+
+/// Split input into a collection name and `#tags`
+pub fn parse_labels(input: &str) -> (Option<String>, Vec<String>) {
+    let mut collection_words = Vec::new();
+    let mut tags: Vec<String> = Vec::new();
+
+    for word in input.split_whitespace() {
+        match word.strip_prefix('#') {
+            Some(tag) if !tag.is_empty() => {
+                if !tags.iter().any(|t| t == tag) {
+                    tags.push(tag.to_string());
+                }
+            }
+            Some(_) => {}
+            None => collection_words.push(word),
+        }
+    }
+
+    let collection = (!collection_words.is_empty()).then(|| collection_words.join(" "));
+    (collection, tags)
+}
+
+/// Format a collection and tags as prompt input
+pub fn format_labels(collection: Option<&str>, tags: &[String]) -> String {
+    let mut parts: Vec<String> = collection.map(str::to_string).into_iter().collect();
+    parts.extend(tags.iter().map(|tag| format!("#{}", tag)));
+    parts.join(" ")
+}
+
+/// Existing names matching what's being typed: tags after a `#`, otherwise collections
+pub fn suggestions(input: &str, collections: &[String], tags: &[String]) -> Vec<String> {
+    let last = input.rsplit(' ').next().unwrap_or("");
+
+    if let Some(prefix) = last.strip_prefix('#') {
+        let (_, used) = parse_labels(input);
+        let prefix = prefix.to_lowercase();
+        return tags
+            .iter()
+            .filter(|tag| tag.to_lowercase().starts_with(&prefix) && !used.contains(tag))
+            .map(|tag| format!("#{}", tag))
+            .collect();
+    }
+
+    // Collections are only suggested before any tags are typed
+    if input.contains('#') {
+        return Vec::new();
+    }
+    let prefix = input.trim_start().to_lowercase();
+    collections
+        .iter()
+        .filter(|name| name.to_lowercase().starts_with(&prefix) && name.to_lowercase() != prefix)
+        .cloned()
+        .collect()
+}
+
+/// Replace the word being typed (or the whole collection name) with a suggestion
+fn apply_suggestion(input: &str, suggestion: &str) -> String {
+    if suggestion.starts_with('#') {
+        let head = input.rsplit_once(' ').map(|(head, _)| head).unwrap_or("");
+        if head.is_empty() {
+            format!("{} ", suggestion)
+        } else {
+            format!("{} {} ", head, suggestion)
+        }
+    } else {
+        format!("{} ", suggestion)
+    }
+}
+
+/// Render the bookmark labels prompt
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let Some(prompt) = &app.bookmark_prompt else {
+        return;
+    };
+
+    // Create centered modal (60% width, 40% height)
+    let modal_area = centered_rect(60, 40, area);
+
+    // Clear the area behind the modal
+    f.render_widget(Clear, modal_area);
+
+    let title = if prompt.editing {
+        format!("{} Labels - {}", icons::BOOKMARK, prompt.label())
+    } else {
+        format!("{} Add Bookmark - {}", icons::BOOKMARK, prompt.label())
+    };
+    let block = app.theme.block_modal_with_title(title);
+    let inner = block.inner(modal_area);
+    f.render_widget(block, modal_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Hint
+            Constraint::Length(2), // Input
+            Constraint::Min(0),    // Suggestions
+            Constraint::Length(1), // Help
+        ])
+        .split(inner);
+
+    f.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            "Collection name, then #tags (both optional)",
+            app.theme.text_muted(),
+        ))),
+        chunks[0],
+    );
+
+    f.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::styled("> ", app.theme.accent()),
+            Span::styled(prompt.input.as_str(), app.theme.text()),
+            Span::styled("█", app.theme.accent()),
+        ])),
+        chunks[1],
+    );
+
+    let collections = app.bookmarks.collections();
+    let tags = app.bookmarks.tags();
+    let lines: Vec<Line> = suggestions(&prompt.input, &collections, &tags)
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .enumerate()
+        .map(|(idx, suggestion)| {
+            let style = if idx == 0 { app.theme.accent() } else { app.theme.text_secondary() };
+            Line::from(Span::styled(format!("  {}", suggestion), style))
+        })
+        .collect();
+    f.render_widget(Paragraph::new(lines), chunks[2]);
+
+    f.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::styled("Tab", app.theme.accent()),
+            Span::styled(":Complete  ", app.theme.text_muted()),
+            Span::styled("Enter", app.theme.accent()),
+            Span::styled(":Save  ", app.theme.text_muted()),
+            Span::styled("ESC", app.theme.accent()),
+            Span::styled(":Cancel", app.theme.text_muted()),
+        ])),
+        chunks[3],
+    );
+}
+
+/// Helper function to create a centered rect
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(popup_layout[1])[1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_complete_labels() {
+        assert_eq!(
+            parse_labels("Sermon series #grace #romans #grace"),
+            (Some("Sermon series".to_string()), vec!["grace".to_string(), "romans".to_string()])
+        );
+        assert_eq!(parse_labels("  "), (None, vec![]));
+        assert_eq!(format_labels(Some("Memory"), &["hope".to_string()]), "Memory #hope");
+
+        let collections = vec!["Memory verses".to_string(), "Sermon series".to_string()];
+        let tags = vec!["grace".to_string(), "gospel".to_string()];
+
+        assert_eq!(suggestions("mem", &collections, &tags), vec!["Memory verses"]);
+        assert_eq!(suggestions("Memory verses #g", &collections, &tags), vec!["#grace", "#gospel"]);
+        assert_eq!(suggestions("x #grace #g", &collections, &tags), vec!["#gospel"]);
+
+        let mut prompt = BookmarkPrompt::new(VerseReference::new("Rom", 8, 28), None, &[], false);
+        prompt.input = "ser".to_string();
+        prompt.complete(&collections, &tags);
+        assert_eq!(prompt.input, "Sermon series ");
+        prompt.input.push_str("#gr");
+        prompt.complete(&collections, &tags);
+        assert_eq!(prompt.input, "Sermon series #grace ");
+    }
+}
@@ -1,39 +1,124 @@
 use crate::app::App;
+use crate::bible::book_name_to_id;
+use crate::config::Bookmark;
 use ratatui::{
-    layout::Rect,
-    text::Line,
-    widgets::{Block, Borders, List, ListItem},
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
     Frame,
 };
 
+/// Order of the bookmarks list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookmarkSort {
+    /// Bible order: Genesis to Revelation
+    Canonical,
+    /// Newest first
+    Created,
+    /// Alphabetical by book name
+    Book,
+}
+
+impl BookmarkSort {
+    /// The next sort order, cycling
+    pub fn next(self) -> Self {
+        match self {
+            BookmarkSort::Canonical => BookmarkSort::Created,
+            BookmarkSort::Created => BookmarkSort::Book,
+            BookmarkSort::Book => BookmarkSort::Canonical,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            BookmarkSort::Canonical => "Canonical",
+            BookmarkSort::Created => "Newest",
+            BookmarkSort::Book => "Book A-Z",
+        }
+    }
+
+    /// Sort bookmarks in place
+    pub fn sort(self, bookmarks: &mut [&Bookmark]) {
+        let canonical = |b: &Bookmark| {
+            (
+                book_name_to_id(&b.reference.book).unwrap_or(u32::MAX),
+                b.reference.chapter,
+                b.reference.verse,
+            )
+        };
+
+        match self {
+            BookmarkSort::Canonical => bookmarks.sort_by_key(|b| canonical(b)),
+            BookmarkSort::Created => bookmarks.sort_by(|a, b| b.created_at.cmp(&a.created_at)),
+            BookmarkSort::Book => bookmarks.sort_by(|a, b| {
+                a.reference
+                    .book
+                    .cmp(&b.reference.book)
+                    .then_with(|| canonical(a).cmp(&canonical(b)))
+            }),
+        }
+    }
+}
+
 /// Render the bookmarks view
 pub fn render(f: &mut Frame, app: &App, area: Rect) {
-    let bookmarks = app.bookmarks.all();
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Filter and sort
+            Constraint::Min(0),    // List
+            Constraint::Length(1), // Help
+        ])
+        .split(area);
+
+    render_filter(f, app, chunks[0]);
+
+    let bookmarks = app.visible_bookmarks();
+    let total = app.bookmarks.all().len();
+
+    // Index of the selected bookmark among list items, past any collection headers
+    let mut selected_item = app.bookmark_selected;
 
     let items: Vec<ListItem> = if bookmarks.is_empty() {
-        vec![ListItem::new(Line::from(
-            "No bookmarks yet. Press 'm' or Ctrl+B to bookmark a verse.",
-        ))]
+        let message = if total == 0 {
+            "No bookmarks yet. Press 'm' to bookmark a verse."
+        } else {
+            "No bookmarks match the filter."
+        };
+        vec![ListItem::new(Line::from(Span::styled(message, app.theme.text_muted())))]
     } else {
-        bookmarks
-            .iter()
-            .enumerate()
-            .map(|(i, bookmark)| {
-                let style = if i == app.bookmark_selected {
-                    app.theme.highlight_style()
-                } else {
-                    app.theme.text_style()
-                };
-
-                let text = if let Some(note) = &bookmark.note {
-                    format!("{} - {}", bookmark.reference, note)
-                } else {
-                    bookmark.reference.to_string()
-                };
-
-                ListItem::new(text).style(style)
-            })
-            .collect()
+        let mut items = Vec::new();
+        let mut current_group: Option<Option<&str>> = None;
+
+        for (idx, bookmark) in bookmarks.iter().enumerate() {
+            // Grouped view: a header row whenever the collection changes
+            let group = bookmark.collection.as_deref();
+            if app.bookmark_grouped && current_group != Some(group) {
+                current_group = Some(group);
+                let name = group.unwrap_or("Uncollected");
+                let count = bookmarks
+                    .iter()
+                    .filter(|b| b.collection.as_deref() == group)
+                    .count();
+                items.push(ListItem::new(Line::from(Span::styled(
+                    format!("▾ {} ({})", name, count),
+                    app.theme.accent(),
+                ))));
+                if idx <= app.bookmark_selected {
+                    selected_item += 1;
+                }
+            }
+            items.push(bookmark_item(app, bookmark));
+        }
+        items
+    };
+
+    let title = if app.bookmark_grouped {
+        format!(" Bookmarks by Collection ({}) - ESC to close ", bookmarks.len())
+    } else if bookmarks.len() == total {
+        format!(" Bookmarks ({}) - ESC to close ", total)
+    } else {
+        format!(" Bookmarks ({} of {}) - ESC to close ", bookmarks.len(), total)
     };
 
     let list = List::new(items)
@@ -41,9 +126,99 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
             Block::default()
                 .borders(Borders::ALL)
                 .border_style(app.theme.border_style())
-                .title(format!(" Bookmarks ({}) - ESC to close ", bookmarks.len())),
+                .title(title),
         )
-        .style(app.theme.text_style());
+        .style(app.theme.text_style())
+        .highlight_style(app.theme.highlight_style())
+        .highlight_symbol("▶ ");
+
+    let mut state = ListState::default();
+    if !bookmarks.is_empty() {
+        state.select(Some(selected_item));
+    }
+    f.render_stateful_widget(list, chunks[1], &mut state);
+
+    let help = match &app.bookmark_undo {
+        Some((_, bookmark)) => Line::from(vec![
+            Span::styled(format!(" Deleted {}  ", bookmark.passage()), app.theme.warning()),
+            Span::styled("u", app.theme.accent()),
+            Span::styled(":Undo", app.theme.text_muted()),
+        ]),
+        None => Line::from(vec![
+            Span::styled(" Enter", app.theme.accent()),
+            Span::styled(":Go  ", app.theme.text_muted()),
+            Span::styled("d", app.theme.accent()),
+            Span::styled(":Delete  ", app.theme.text_muted()),
+            Span::styled("e", app.theme.accent()),
+            Span::styled(":Note  ", app.theme.text_muted()),
+            Span::styled("t", app.theme.accent()),
+            Span::styled(":Labels  ", app.theme.text_muted()),
+            Span::styled("c", app.theme.accent()),
+            Span::styled(":Group  ", app.theme.text_muted()),
+            Span::styled("Tab", app.theme.accent()),
+            Span::styled(":Sort  ", app.theme.text_muted()),
+            Span::styled("/", app.theme.accent()),
+            Span::styled(":Filter", app.theme.text_muted()),
+        ]),
+    };
+    f.render_widget(Paragraph::new(help), chunks[2]);
+}
+
+/// A bookmark's list entry: reference, labels and note, then a preview line
+fn bookmark_item<'a>(app: &'a App, bookmark: &'a Bookmark) -> ListItem<'a> {
+    let mut header = vec![Span::styled(bookmark.passage().to_string(), app.theme.heading())];
+    if !app.bookmark_grouped {
+        if let Some(collection) = &bookmark.collection {
+            header.push(Span::styled(format!("  [{}]", collection), app.theme.text_secondary()));
+        }
+    }
+    for tag in &bookmark.tags {
+        header.push(Span::styled(format!(" #{}", tag), app.theme.accent()));
+    }
+    if let Some(note) = &bookmark.note {
+        header.push(Span::styled(" - ", app.theme.text_muted()));
+        header.push(Span::styled(
+            note.lines().next().unwrap_or_default().to_string(),
+            app.theme.text(),
+        ));
+    }
+
+    let preview = app
+        .verse_previews
+        .get(&bookmark.reference)
+        .map(String::as_str)
+        .unwrap_or("");
+
+    // Ranges preview their first verse
+    let ellipsis = if bookmark.end.is_some() { " …" } else { "" };
+
+    ListItem::new(vec![
+        Line::from(header),
+        Line::from(Span::styled(
+            format!("    {}{}", preview, ellipsis),
+            app.theme.text_muted(),
+        )),
+    ])
+}
+
+/// Render the filter box with the current sort order
+fn render_filter(f: &mut Frame, app: &App, area: Rect) {
+    let filter = if app.bookmark_filter.is_empty() && !app.bookmark_filter_active {
+        Span::styled("Press / to filter, /# for tags", app.theme.text_muted())
+    } else {
+        Span::styled(app.bookmark_filter.as_str(), app.theme.text())
+    };
+
+    let mut spans = vec![filter];
+    if app.bookmark_filter_active {
+        spans.push(Span::styled("█", app.theme.accent()));
+    }
+
+    let block = if app.bookmark_filter_active {
+        app.theme.block_active_with_title(format!(" Filter - Sort: {} ", app.bookmark_sort.label()))
+    } else {
+        app.theme.block_with_title(format!(" Filter - Sort: {} ", app.bookmark_sort.label()))
+    };
 
-    f.render_widget(list, area);
+    f.render_widget(Paragraph::new(Line::from(spans)).block(block), area);
 }
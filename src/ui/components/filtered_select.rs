@@ -225,11 +225,14 @@ pub struct NumberGrid;
 
 impl NumberGrid {
     /// Render a number grid (for chapters or verses)
+    ///
+    /// Numbers in `marked` (e.g. chapters already read) are drawn in the success color.
     pub fn render(
         f: &mut Frame,
         title: &str,
         max_number: usize,
         selected: usize,
+        marked: &[u32],
         theme: &Theme,
         area: Rect,
     ) {
@@ -264,6 +267,8 @@ impl NumberGrid {
             let num_str = format!("{:>3}", num);
             let style = if is_selected {
                 theme.selected()
+            } else if marked.contains(&(num as u32)) {
+                theme.success()
             } else {
                 theme.text_secondary()
             };
@@ -0,0 +1,165 @@
+use crate::app::App;
+use crate::bible::search::{kwic, Concordance};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Bar, BarChart, BarGroup, Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+/// Width of the reference column
+const REFERENCE_WIDTH: usize = 12;
+
+/// Render the concordance view: the word, its occurrences and their
+/// distribution across books
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Word input
+            Constraint::Min(0),    // Occurrences and chart
+        ])
+        .split(area);
+
+    render_input(f, app, chunks[0]);
+
+    let Some(concordance) = app.concordance.as_ref().filter(|c| c.total() > 0) else {
+        let hint = Paragraph::new("Type a word and press Enter, or press c on a word in word mode (w)")
+            .style(app.theme.text_muted())
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(app.theme.border_style())
+                    .title(" Occurrences "),
+            );
+        f.render_widget(hint, chunks[1]);
+        return;
+    };
+
+    let body = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+        .split(chunks[1]);
+
+    render_occurrences(f, app, concordance, body[0]);
+    render_distribution(f, app, concordance, body[1]);
+}
+
+/// Render the word input field
+fn render_input(f: &mut Frame, app: &App, area: Rect) {
+    let input = Paragraph::new(app.concordance_query.as_str())
+        .style(app.theme.text_style())
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(app.theme.border_style())
+                .title(" Concordance (Enter to build, Tab grouped/KWIC, ESC to close) "),
+        );
+
+    f.render_widget(input, area);
+    let width = Span::raw(app.concordance_query.as_str()).width() as u16;
+    f.set_cursor_position((area.x + width + 1, area.y + 1));
+}
+
+/// Render the occurrences, either under a header per book or as
+/// keyword-in-context lines with the word aligned in a column
+fn render_occurrences(f: &mut Frame, app: &App, concordance: &Concordance, area: Rect) {
+    // Context either side of the word, inside the borders and highlight symbol
+    let word_width = concordance.word.chars().count();
+    let context = (area.width as usize).saturating_sub(4 + REFERENCE_WIDTH + word_width) / 2;
+
+    let mut items = Vec::new();
+    let mut selected_item = None;
+    let mut index = 0;
+
+    for (book, occurrences) in &concordance.books {
+        if !app.concordance_kwic {
+            let times = if occurrences.len() == 1 { "occurrence" } else { "occurrences" };
+            items.push(ListItem::new(Line::from(vec![
+                Span::styled(book.as_str(), app.theme.heading()),
+                Span::styled(format!("  {} {}", occurrences.len(), times), app.theme.text_muted()),
+            ])));
+        }
+
+        for occurrence in occurrences {
+            if index == app.concordance_selected {
+                selected_item = Some(items.len());
+            }
+            index += 1;
+
+            let (start, end) = (occurrence.start, occurrence.end);
+            let text = app
+                .verse_previews
+                .get(&occurrence.reference)
+                .map(String::as_str)
+                .unwrap_or("");
+            let (left, word, right) = match text.get(start..end) {
+                Some(_) if app.concordance_kwic => {
+                    let (left, word, right) = kwic(text, start, end, context);
+                    (format!("{:>width$}", left, width = context), word, right)
+                }
+                Some(word) => (text[..start].to_string(), word, text[end..].to_string()),
+                None => (text.to_string(), "", String::new()),
+            };
+            items.push(ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:<width$}", occurrence.reference.to_string(), width = REFERENCE_WIDTH),
+                    app.theme.accent(),
+                ),
+                Span::styled(left, app.theme.text_secondary()),
+                Span::styled(word, app.theme.heading()),
+                Span::styled(right, app.theme.text_secondary()),
+            ])));
+        }
+    }
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(app.theme.border_style())
+                .title(format!(
+                    " \"{}\" - {} in {} books ",
+                    concordance.word,
+                    concordance.total(),
+                    concordance.books.len()
+                )),
+        )
+        .style(app.theme.text_style())
+        .highlight_style(app.theme.highlight_style());
+
+    let mut state = ListState::default();
+    state.select(selected_item);
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+/// Bar chart of occurrences per book, in Bible order
+fn render_distribution(f: &mut Frame, app: &App, concordance: &Concordance, area: Rect) {
+    let bars: Vec<Bar> = concordance
+        .books
+        .iter()
+        .map(|(book, occurrences)| {
+            Bar::default()
+                .value(occurrences.len() as u64)
+                .label(Line::from(book.as_str()))
+                .text_value(occurrences.len().to_string())
+        })
+        .collect();
+
+    let chart = BarChart::default()
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(app.theme.border_style())
+                .title(" By book "),
+        )
+        .direction(Direction::Horizontal)
+        .data(BarGroup::default().bars(&bars))
+        .bar_width(1)
+        .bar_gap(0)
+        .bar_style(Style::default().fg(app.theme.accent_primary))
+        .value_style(Style::default().fg(app.theme.text_inverse).bg(app.theme.accent_primary))
+        .label_style(app.theme.text_muted());
+    f.render_widget(chart, area);
+}
@@ -0,0 +1,89 @@
+use crate::app::App;
+use crate::bible::BOOK_ORDER;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+/// Width of the book label column
+const LABEL_WIDTH: usize = 8;
+
+/// Render the whole-Bible coverage map: one row per book, one cell per chapter
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let total_chapters: u32 = BOOK_ORDER.iter().map(|(_, _, _, count)| *count).sum();
+    let (cursor_book, _, _, _) = BOOK_ORDER[app.coverage_book];
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(app.theme.border_style())
+        .title(format!(
+            " Coverage: {}/{} chapters read - {} {} - Enter:Go r:Toggle ESC:Close ",
+            app.read_chapters.total(),
+            total_chapters,
+            cursor_book,
+            app.coverage_chapter
+        ));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    // Long books (e.g. Psalms) wrap onto several rows
+    let per_row = (inner.width as usize).saturating_sub(LABEL_WIDTH).max(1);
+
+    let read_style = app.theme.success();
+    let unread_style = Style::default().fg(app.theme.bg_selection);
+    let cursor_style = Style::default()
+        .fg(app.theme.accent_primary)
+        .add_modifier(Modifier::REVERSED);
+
+    let mut lines = Vec::new();
+    let mut cursor_line = 0;
+
+    for (book_idx, (book, _, _, chapter_count)) in BOOK_ORDER.iter().enumerate() {
+        let chapters: Vec<u32> = (1..=*chapter_count).collect();
+
+        for (row_idx, row) in chapters.chunks(per_row).enumerate() {
+            let label = if row_idx == 0 { *book } else { "" };
+            let label_style = if book_idx == app.coverage_book {
+                app.theme.accent()
+            } else {
+                app.theme.text_muted()
+            };
+            let mut spans = vec![Span::styled(
+                format!("{:<width$}", label, width = LABEL_WIDTH),
+                label_style,
+            )];
+
+            for chapter in row {
+                let is_cursor = book_idx == app.coverage_book && *chapter == app.coverage_chapter;
+                if is_cursor {
+                    cursor_line = lines.len();
+                }
+
+                let style = if is_cursor {
+                    cursor_style
+                } else if app.read_chapters.is_read(book, *chapter) {
+                    read_style
+                } else {
+                    unread_style
+                };
+                spans.push(Span::styled("■", style));
+            }
+
+            lines.push(Line::from(spans));
+        }
+    }
+
+    // Keep the cursor row on screen
+    let height = inner.height as usize;
+    let scroll = if cursor_line >= height {
+        (cursor_line + 1 - height / 2).min(lines.len().saturating_sub(height))
+    } else {
+        0
+    };
+
+    let paragraph = Paragraph::new(lines).scroll((scroll as u16, 0));
+    f.render_widget(paragraph, inner);
+}
@@ -0,0 +1,73 @@
+use crate::app::App;
+use crate::bible::content::NoteKind;
+use crate::ui::icons;
+use crate::ui::reader::note_marker;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// Render the footnotes popup for the current verse
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let Some(verse) = app.current_verse() else {
+        return;
+    };
+
+    let modal_area = centered_rect(60, 50, area);
+
+    // Clear the area behind the modal
+    f.render_widget(Clear, modal_area);
+
+    let mut lines = vec![Line::from("")];
+    for (index, note) in verse.content.notes.iter().enumerate() {
+        let kind = match note.kind {
+            NoteKind::Footnote => "",
+            NoteKind::CrossReference => "See ",
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!(" {} ", note_marker(index)), app.theme.accent()),
+            Span::styled(kind, app.theme.text_muted()),
+            Span::styled(note.text.clone(), app.theme.text()),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("ESC", app.theme.accent()),
+        Span::styled(":Close", app.theme.text_muted()),
+    ]));
+
+    let block = app
+        .theme
+        .block_modal_with_title(format!("{} Notes on {}", icons::NOTE, verse.reference));
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .style(app.theme.text())
+        .wrap(Wrap { trim: false });
+
+    f.render_widget(paragraph, modal_area);
+}
+
+/// Helper function to create a centered rect
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(popup_layout[1])[1]
+}
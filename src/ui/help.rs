@@ -66,10 +66,36 @@ fn help_text_content(app: &App) -> Vec<Line<'static>> {
             Span::styled("FEATURES", app.theme.text_secondary()),
         ]),
         Line::from("  g             - Go to (book/chapter/verse selector)"),
-        Line::from("  /             - Search verses"),
+        Line::from("  /             - Search verses (strong:G26 or lemma:agape for Strong's)"),
         Line::from("  n/N           - Next/previous search result"),
-        Line::from("  m             - Toggle bookmark on current verse"),
-        Line::from("  b             - View bookmarks"),
+        Line::from("  W             - Concordance of a word (Tab grouped/KWIC lines)"),
+        Line::from("  m             - Toggle bookmark (prompts for collection and #tags)"),
+        Line::from("  M             - Select a range: press on the first verse, then M on"),
+        Line::from("                  the last to bookmark it (or H to highlight it)"),
+        Line::from("  H             - Highlight verse or range (1-5 color, x clear)"),
+        Line::from("  L             - View highlights (Tab/1-5 filter by color, d delete)"),
+        Line::from("  w             - Word cursor in current verse: h/l move, v phrase,"),
+        Line::from("                  u underline, b bold, 1-5 color, x clear,"),
+        Line::from("                  Enter Strong's lexicon, c concordance, Esc done"),
+        Line::from("  x             - Cross-references panel (Tab focus, Enter follow)"),
+        Line::from("  f             - Footnotes on the current verse (ᵃ markers)"),
+        Line::from("  o             - Outline of the book's section headings (Tab focus,"),
+        Line::from("                  Enter jump)"),
+        Line::from("  K             - Commentary pane for the current verse (Tab focus,"),
+        Line::from("                  h/l switch module, j/k scroll)"),
+        Line::from("  A             - Analytics of the chapter or book: word counts, reading"),
+        Line::from("                  time, frequent and distinctive words (Tab focus,"),
+        Line::from("                  h/l chapter/book, j/k scroll)"),
+        Line::from("  Backspace     - Go back to where a cross-reference was followed from"),
+        Line::from("  b             - View bookmarks (Enter go, d delete, u undo, e note,"),
+        Line::from("                  t labels, c group by collection, Tab sort,"),
+        Line::from("                  / filter, /#tag filter by tag)"),
+        Line::from("  e             - Edit note on current verse (Ctrl+S saves)"),
+        Line::from("  E             - Edit note in $VISUAL/$EDITOR"),
+        Line::from("  p             - Reading plan (today's readings)"),
+        Line::from("  r             - Toggle current chapter as read"),
+        Line::from("  C             - Coverage map of chapters read"),
+        Line::from("  S             - Reading statistics"),
         Line::from("  s             - Open settings"),
         Line::from(""),
         Line::from(vec![
@@ -83,7 +109,8 @@ fn help_text_content(app: &App) -> Vec<Line<'static>> {
             Span::styled("TIPS", app.theme.accent()),
         ]),
         Line::from("  • Both vim (j/k) and arrow keys work everywhere"),
-        Line::from("  • Press 'g' to quickly jump to any book/chapter/verse"),
+        Line::from("  • Press 'g' to quickly jump to any book/chapter/verse, or type a"),
+        Line::from("    section heading such as 'sermon' to jump to it"),
         Line::from("  • In the selector: arrow keys navigate, letters filter"),
         Line::from("  • Bookmarks ('m') persist across sessions"),
         Line::from("  • Search ('/') works across the entire Bible"),
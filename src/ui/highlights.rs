@@ -0,0 +1,186 @@
+use crate::app::App;
+use crate::config::HighlightColor;
+use crate::ui::icons;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+/// Render the highlights view
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Color filter
+            Constraint::Min(0),    // List
+            Constraint::Length(1), // Help
+        ])
+        .split(area);
+
+    render_filter(f, app, chunks[0]);
+
+    let highlights = app.visible_highlights();
+    let total = app.highlights.all().len();
+
+    let items: Vec<ListItem> = if highlights.is_empty() {
+        let message = if total == 0 {
+            "No highlights yet. Press 'H' to highlight a verse."
+        } else {
+            "No highlights in this color."
+        };
+        vec![ListItem::new(Line::from(Span::styled(message, app.theme.text_muted())))]
+    } else {
+        highlights
+            .iter()
+            .map(|highlight| {
+                let swatch = Style::default().bg(app.theme.highlighter(highlight.color));
+                let preview = app
+                    .verse_previews
+                    .get(&highlight.start)
+                    .map(String::as_str)
+                    .unwrap_or("");
+                let ellipsis = if highlight.end.is_some() { " …" } else { "" };
+
+                ListItem::new(vec![
+                    Line::from(vec![
+                        Span::styled("  ", swatch),
+                        Span::raw(" "),
+                        Span::styled(highlight.passage().to_string(), app.theme.heading()),
+                        Span::styled(format!("  {}", highlight.color.name()), app.theme.text_muted()),
+                    ]),
+                    Line::from(Span::styled(
+                        format!("    {}{}", preview, ellipsis),
+                        app.theme.text_muted(),
+                    )),
+                ])
+            })
+            .collect()
+    };
+
+    let title = if highlights.len() == total {
+        format!(" Highlights ({}) - ESC to close ", total)
+    } else {
+        format!(" Highlights ({} of {}) - ESC to close ", highlights.len(), total)
+    };
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(app.theme.border_style())
+                .title(title),
+        )
+        .style(app.theme.text_style())
+        .highlight_style(app.theme.highlight_style())
+        .highlight_symbol("▶ ");
+
+    let mut state = ListState::default();
+    if !highlights.is_empty() {
+        state.select(Some(app.highlight_selected));
+    }
+    f.render_stateful_widget(list, chunks[1], &mut state);
+
+    let help = Line::from(vec![
+        Span::styled(" Enter", app.theme.accent()),
+        Span::styled(":Go  ", app.theme.text_muted()),
+        Span::styled("d", app.theme.accent()),
+        Span::styled(":Delete  ", app.theme.text_muted()),
+        Span::styled("Tab", app.theme.accent()),
+        Span::styled(":Next color  ", app.theme.text_muted()),
+        Span::styled("1-5", app.theme.accent()),
+        Span::styled(":Color  ", app.theme.text_muted()),
+        Span::styled("0", app.theme.accent()),
+        Span::styled(":All", app.theme.text_muted()),
+    ]);
+    f.render_widget(Paragraph::new(help), chunks[2]);
+}
+
+/// Render the color filter row: every color, with the active one marked
+fn render_filter(f: &mut Frame, app: &App, area: Rect) {
+    let mut spans = vec![filter_label(app, "All", app.highlight_filter.is_none())];
+    for (idx, color) in HighlightColor::ALL.iter().enumerate() {
+        spans.push(Span::raw("  "));
+        spans.push(Span::styled("  ", Style::default().bg(app.theme.highlighter(*color))));
+        spans.push(filter_label(
+            app,
+            &format!(" {} {}", idx + 1, color.name()),
+            app.highlight_filter == Some(*color),
+        ));
+    }
+
+    let block = app.theme.block_with_title(" Filter by Color ");
+    f.render_widget(Paragraph::new(Line::from(spans)).block(block), area);
+}
+
+fn filter_label(app: &App, label: &str, active: bool) -> Span<'static> {
+    let style = if active { app.theme.accent() } else { app.theme.text_muted() };
+    Span::styled(label.to_string(), style)
+}
+
+/// Render the highlighter color picker modal
+pub fn render_picker(f: &mut Frame, app: &App, area: Rect) {
+    let height = HighlightColor::ALL.len() as u16 + 5;
+    let modal_area = centered_rect(34, height, area);
+
+    // Clear the area behind the modal
+    f.render_widget(Clear, modal_area);
+
+    let title = match &app.selection_anchor {
+        Some(anchor) => format!("{} Highlight from {}", icons::HIGHLIGHTER, anchor),
+        None => format!("{} Highlight", icons::HIGHLIGHTER),
+    };
+    let block = app.theme.block_modal_with_title(title);
+    let inner = block.inner(modal_area);
+    f.render_widget(block, modal_area);
+
+    let mut lines: Vec<Line> = HighlightColor::ALL
+        .iter()
+        .enumerate()
+        .map(|(idx, color)| {
+            let selected = idx == app.highlight_picker_index;
+            Line::from(vec![
+                Span::styled(if selected { "▶ " } else { "  " }, app.theme.accent()),
+                Span::styled(format!("{} ", idx + 1), app.theme.accent()),
+                Span::styled("    ", Style::default().bg(app.theme.highlighter(*color))),
+                Span::styled(
+                    format!(" {}", color.name()),
+                    if selected { app.theme.selected() } else { app.theme.text() },
+                ),
+            ])
+        })
+        .collect();
+
+    let clear_selected = app.highlight_picker_index == HighlightColor::ALL.len();
+    lines.push(Line::from(vec![
+        Span::styled(if clear_selected { "▶ " } else { "  " }, app.theme.accent()),
+        Span::styled("x ", app.theme.accent()),
+        Span::styled(
+            "Clear highlight",
+            if clear_selected { app.theme.selected() } else { app.theme.text() },
+        ),
+    ]));
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("Enter", app.theme.accent()),
+        Span::styled(":Apply  ", app.theme.text_muted()),
+        Span::styled("ESC", app.theme.accent()),
+        Span::styled(":Cancel", app.theme.text_muted()),
+    ]));
+
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
+/// A rect of fixed size centered in `r`
+fn centered_rect(width: u16, height: u16, r: Rect) -> Rect {
+    let width = width.min(r.width);
+    let height = height.min(r.height);
+    Rect::new(
+        r.x + (r.width - width) / 2,
+        r.y + (r.height - height) / 2,
+        width,
+        height,
+    )
+}
@@ -11,6 +11,11 @@ pub const BOOK_MULTIPLE: &str = "󱓷";  // nf-md-book_multiple
 pub const BIBLE: &str = "󰮭";  // nf-md-book_cross
 pub const BOOKMARK: &str = "󰃃";  // nf-md-bookmark
 pub const BOOKMARK_OUTLINE: &str = "󰃀";  // nf-md-bookmark_outline
+pub const NOTE: &str = "󰎞";  // nf-md-note_text
+pub const HIGHLIGHTER: &str = "󰸱";  // nf-md-marker
+pub const OUTLINE: &str = "󰉹";  // nf-md-format_list_bulleted
+pub const COMMENTARY: &str = "󰈙";  // nf-md-file_document
+pub const CHART: &str = "󰄨";  // nf-md-chart_bar
 
 // Navigation Icons
 pub const CHEVRON_RIGHT: &str = "";  // nf-fa-chevron_right
@@ -0,0 +1,98 @@
+use crate::app::App;
+use crate::ui::icons;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// Render the lexicon popup for the word under the word cursor
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let Some(popup) = &app.lexicon_popup else {
+        return;
+    };
+
+    let modal_area = centered_rect(60, 60, area);
+
+    // Clear the area behind the modal
+    f.render_widget(Clear, modal_area);
+
+    let mut lines = Vec::new();
+    for (number, entry, count) in &popup.entries {
+        if !lines.is_empty() {
+            lines.push(Line::from(""));
+        }
+
+        let occurrences = match count {
+            1 => "1 occurrence".to_string(),
+            count => format!("{} occurrences", count),
+        };
+        let mut heading = vec![Span::styled(number.clone(), app.theme.accent())];
+        if let Some(entry) = entry {
+            heading.push(Span::styled(format!("  {}", entry.lemma), app.theme.heading()));
+        }
+        heading.push(Span::styled(format!("  {}", occurrences), app.theme.text_muted()));
+        lines.push(Line::from(heading));
+
+        let Some(entry) = entry else {
+            lines.push(Line::from(Span::styled(
+                "Not in the lexicon; run 'biblios import-lexicon <file>'",
+                app.theme.text_muted(),
+            )));
+            continue;
+        };
+
+        for (label, value) in [
+            ("Transliteration: ", &entry.transliteration),
+            ("Pronunciation: ", &entry.pronunciation),
+            ("Definition: ", &entry.definition),
+            ("KJV: ", &entry.kjv_usage),
+        ] {
+            if !value.is_empty() {
+                lines.push(Line::from(vec![
+                    Span::styled(label, app.theme.text_secondary()),
+                    Span::styled(value.clone(), app.theme.text()),
+                ]));
+            }
+        }
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("ESC", app.theme.accent()),
+        Span::styled(":Close", app.theme.text_muted()),
+    ]));
+
+    let block = app
+        .theme
+        .block_modal_with_title(format!("{} {}", icons::BOOK, popup.word));
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .style(app.theme.text())
+        .wrap(Wrap { trim: false });
+
+    f.render_widget(paragraph, modal_area);
+}
+
+/// Helper function to create a centered rect
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(popup_layout[1])[1]
+}
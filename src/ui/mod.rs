@@ -1,10 +1,19 @@
+pub mod bookmark_prompt;
 pub mod bookmarks;
 pub mod components;
+pub mod concordance;
+pub mod coverage;
+pub mod footnotes;
 pub mod help;
+pub mod highlights;
 pub mod icons;
+pub mod lexicon;
+pub mod note_editor;
 pub mod reader;
+pub mod reading_plan;
 pub mod search;
 pub mod settings;
+pub mod stats;
 pub mod theme_picker;
 pub mod themes;
 pub mod verse_selector;
@@ -48,6 +57,8 @@ fn render_keybindings(f: &mut Frame, app: &App, area: Rect) {
         Span::styled(":Bookmarks ", app.theme.text_muted()),
         Span::styled("/", app.theme.accent()),
         Span::styled(":Search ", app.theme.text_muted()),
+        Span::styled("p", app.theme.accent()),
+        Span::styled(":Plan ", app.theme.text_muted()),
         Span::styled("s", app.theme.accent()),
         Span::styled(":Settings ", app.theme.text_muted()),
         Span::styled("?", app.theme.accent()),
@@ -83,6 +94,10 @@ fn render_content(f: &mut Frame, app: &App, area: Rect) {
         ViewMode::Reader => reader::render(f, app, area),
         ViewMode::Search => search::render(f, app, area),
         ViewMode::Bookmarks => bookmarks::render(f, app, area),
+        ViewMode::Stats => stats::render(f, app, area),
+        ViewMode::Coverage => coverage::render(f, app, area),
+        ViewMode::Highlights => highlights::render(f, app, area),
+        ViewMode::Concordance => concordance::render(f, app, area),
         ViewMode::Settings => {
             // Settings is now a modal, so render reader underneath
             reader::render(f, app, area);
@@ -108,6 +123,36 @@ fn render_content(f: &mut Frame, app: &App, area: Rect) {
         theme_picker::render(f, app, f.area());
     }
 
+    // Render reading plan modal on top if open
+    if app.plan_open {
+        reading_plan::render(f, app, f.area());
+    }
+
+    // Render highlighter color picker on top if open
+    if app.highlight_picker_open {
+        highlights::render_picker(f, app, f.area());
+    }
+
+    // Render bookmark labels prompt on top if open
+    if app.bookmark_prompt.is_some() {
+        bookmark_prompt::render(f, app, f.area());
+    }
+
+    // Render note editor modal on top if open
+    if app.note_editor.is_some() {
+        note_editor::render(f, app, f.area());
+    }
+
+    // Render footnotes popup on top if open
+    if app.footnotes_open {
+        footnotes::render(f, app, f.area());
+    }
+
+    // Render lexicon popup on top if open
+    if app.lexicon_popup.is_some() {
+        lexicon::render(f, app, f.area());
+    }
+
     // Render help modal on top if open
     if app.help_open {
         help::render(f, app, f.area());
@@ -116,11 +161,16 @@ fn render_content(f: &mut Frame, app: &App, area: Rect) {
 
 /// Render the footer with status information
 fn render_footer(f: &mut Frame, app: &App, area: Rect) {
-    let status = format!(
+    let mut status = format!(
         " {} | Theme: {} ",
         app.settings.translation,
         app.theme.name
     );
+    if let Some(message) = &app.status_message {
+        status.push_str(&format!("| {} ", message));
+    } else if app.word_cursor.is_some() {
+        status.push_str("| WORDS  h/l move  v phrase  u underline  b bold  1-5 color  x clear  Enter lexicon  c concordance  Esc done ");
+    }
 
     let footer = Paragraph::new(status)
         .style(app.theme.text_secondary().bg(app.theme.bg_secondary));
@@ -0,0 +1,291 @@
+use crate::app::App;
+use crate::bible::VerseReference;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Position, Rect},
+    text::{Line, Span},
+    widgets::{Clear, Paragraph},
+    Frame,
+};
+
+/// Where an edited note is saved
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteTarget {
+    /// The verse's study note in the notes store
+    Verse,
+    /// The note attached to the verse's bookmark
+    Bookmark,
+}
+
+/// Multi-line text being edited for a verse note
+///
+/// The cursor is a (line, column) position where the column counts chars,
+/// not bytes, so editing is safe with non-ASCII text.
+#[derive(Debug, Clone)]
+pub struct NoteEditor {
+    pub reference: VerseReference,
+    pub target: NoteTarget,
+    pub lines: Vec<String>,
+    pub cursor_line: usize,
+    pub cursor_col: usize,
+}
+
+impl NoteEditor {
+    /// Start editing with existing text, cursor at the end
+    pub fn new(reference: VerseReference, target: NoteTarget, text: &str) -> Self {
+        let mut lines: Vec<String> = text.lines().map(str::to_string).collect();
+        if lines.is_empty() {
+            lines.push(String::new());
+        }
+        let cursor_line = lines.len() - 1;
+        let cursor_col = lines[cursor_line].chars().count();
+
+        Self {
+            reference,
+            target,
+            lines,
+            cursor_line,
+            cursor_col,
+        }
+    }
+
+    /// The edited text
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// Insert a character at the cursor
+    pub fn insert_char(&mut self, c: char) {
+        let idx = self.byte_index();
+        self.lines[self.cursor_line].insert(idx, c);
+        self.cursor_col += 1;
+    }
+
+    /// Split the current line at the cursor
+    pub fn newline(&mut self) {
+        let idx = self.byte_index();
+        let rest = self.lines[self.cursor_line].split_off(idx);
+        self.cursor_line += 1;
+        self.lines.insert(self.cursor_line, rest);
+        self.cursor_col = 0;
+    }
+
+    /// Delete the character before the cursor, joining lines at the start of a line
+    pub fn backspace(&mut self) {
+        if self.cursor_col > 0 {
+            self.cursor_col -= 1;
+            let idx = self.byte_index();
+            self.lines[self.cursor_line].remove(idx);
+        } else if self.cursor_line > 0 {
+            let line = self.lines.remove(self.cursor_line);
+            self.cursor_line -= 1;
+            self.cursor_col = self.lines[self.cursor_line].chars().count();
+            self.lines[self.cursor_line].push_str(&line);
+        }
+    }
+
+    /// Delete the character under the cursor, joining lines at the end of a line
+    pub fn delete(&mut self) {
+        if self.cursor_col < self.line_len() {
+            let idx = self.byte_index();
+            self.lines[self.cursor_line].remove(idx);
+        } else if self.cursor_line + 1 < self.lines.len() {
+            let next = self.lines.remove(self.cursor_line + 1);
+            self.lines[self.cursor_line].push_str(&next);
+        }
+    }
+
+    pub fn move_left(&mut self) {
+        if self.cursor_col > 0 {
+            self.cursor_col -= 1;
+        } else if self.cursor_line > 0 {
+            self.cursor_line -= 1;
+            self.cursor_col = self.line_len();
+        }
+    }
+
+    pub fn move_right(&mut self) {
+        if self.cursor_col < self.line_len() {
+            self.cursor_col += 1;
+        } else if self.cursor_line + 1 < self.lines.len() {
+            self.cursor_line += 1;
+            self.cursor_col = 0;
+        }
+    }
+
+    pub fn move_up(&mut self) {
+        if self.cursor_line > 0 {
+            self.cursor_line -= 1;
+            self.cursor_col = self.cursor_col.min(self.line_len());
+        }
+    }
+
+    pub fn move_down(&mut self) {
+        if self.cursor_line + 1 < self.lines.len() {
+            self.cursor_line += 1;
+            self.cursor_col = self.cursor_col.min(self.line_len());
+        }
+    }
+
+    pub fn move_home(&mut self) {
+        self.cursor_col = 0;
+    }
+
+    pub fn move_end(&mut self) {
+        self.cursor_col = self.line_len();
+    }
+
+    /// Length of the current line in chars
+    fn line_len(&self) -> usize {
+        self.lines[self.cursor_line].chars().count()
+    }
+
+    /// Byte offset of the cursor within the current line
+    fn byte_index(&self) -> usize {
+        let line = &self.lines[self.cursor_line];
+        line.char_indices()
+            .nth(self.cursor_col)
+            .map(|(idx, _)| idx)
+            .unwrap_or(line.len())
+    }
+}
+
+/// Render the note editor modal
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let Some(editor) = &app.note_editor else {
+        return;
+    };
+
+    // Create centered modal (70% width, 60% height)
+    let modal_area = centered_rect(70, 60, area);
+
+    // Clear the area behind the modal
+    f.render_widget(Clear, modal_area);
+
+    let kind = match editor.target {
+        NoteTarget::Verse => "Note",
+        NoteTarget::Bookmark => "Bookmark Note",
+    };
+    let block = app
+        .theme
+        .block_modal_with_title(format!("✎ {} - {}", kind, editor.reference));
+    let inner = block.inner(modal_area);
+    f.render_widget(block, modal_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner);
+    let text_area = chunks[0];
+    let width = (text_area.width as usize).max(1);
+
+    // Wrap each line at word boundaries, tracking where the cursor lands
+    let mut lines = Vec::new();
+    let mut cursor = (0, 0);
+    for (line_idx, line) in editor.lines.iter().enumerate() {
+        let segments = wrap_line(line, width);
+        for (seg_idx, (start, segment)) in segments.iter().enumerate() {
+            let seg_len = segment.chars().count();
+            let is_last = seg_idx + 1 == segments.len();
+            if line_idx == editor.cursor_line
+                && editor.cursor_col >= *start
+                && (editor.cursor_col < start + seg_len || is_last)
+            {
+                cursor = (lines.len(), editor.cursor_col - start);
+            }
+            lines.push(Line::from(Span::styled(segment.clone(), app.theme.text())));
+        }
+    }
+
+    // Scroll so the cursor row stays visible
+    let height = text_area.height as usize;
+    let scroll = (cursor.0 + 1).saturating_sub(height);
+
+    f.render_widget(Paragraph::new(lines).scroll((scroll as u16, 0)), text_area);
+    // A terminal too short to show any text rows has no row for the cursor
+    if height > 0 {
+        f.set_cursor_position(Position::new(
+            text_area.x + cursor.1.min(width - 1) as u16,
+            text_area.y + (cursor.0 - scroll) as u16,
+        ));
+    }
+
+    let help = Line::from(vec![
+        Span::styled("Ctrl+S", app.theme.accent()),
+        Span::styled(":Save  ", app.theme.text_muted()),
+        Span::styled("ESC", app.theme.accent()),
+        Span::styled(":Cancel  ", app.theme.text_muted()),
+        Span::styled("Enter", app.theme.accent()),
+        Span::styled(":New line  ", app.theme.text_muted()),
+        Span::styled("Save empty to delete", app.theme.text_muted()),
+    ]);
+    f.render_widget(Paragraph::new(help), chunks[1]);
+}
+
+/// Split a line into segments no wider than `width`, breaking after spaces
+/// where possible; each segment carries its starting char column
+fn wrap_line(line: &str, width: usize) -> Vec<(usize, String)> {
+    let chars: Vec<char> = line.chars().collect();
+    let mut segments = Vec::new();
+    let mut start = 0;
+
+    while chars.len() - start > width {
+        let limit = start + width;
+        let end = chars[start..limit]
+            .iter()
+            .rposition(|c| *c == ' ')
+            .map(|pos| start + pos + 1)
+            .filter(|end| *end > start)
+            .unwrap_or(limit);
+        segments.push((start, chars[start..end].iter().collect()));
+        start = end;
+    }
+    segments.push((start, chars[start..].iter().collect()));
+
+    segments
+}
+
+/// Helper function to create a centered rect
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(popup_layout[1])[1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_editing_and_wrapping() {
+        let mut editor = NoteEditor::new(VerseReference::new("John", 3, 16), NoteTarget::Verse, "héllo");
+        editor.move_left();
+        editor.newline();
+        editor.insert_char('L');
+        assert_eq!(editor.text(), "héll\nLo");
+
+        editor.move_home();
+        editor.backspace();
+        assert_eq!(editor.text(), "héllLo");
+        assert_eq!((editor.cursor_line, editor.cursor_col), (0, 4));
+
+        assert_eq!(
+            wrap_line("for God so loved", 8),
+            vec![(0, "for God ".to_string()), (8, "so loved".to_string())]
+        );
+        assert_eq!(wrap_line("abcdefghij", 4).len(), 3);
+    }
+}
@@ -1,10 +1,15 @@
-use crate::app::App;
+use crate::app::{App, WordCursor};
+use crate::bible::analytics::WordCounts;
+use crate::bible::content::{paragraph_starts, TitleKind};
+use crate::bible::{Chapter, Verse};
+use crate::config::settings::ReadingLayout;
+use crate::config::word_marks::word_ranges;
 use crate::ui::icons;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::Style,
+    style::{Modifier, Style},
     text::{Line, Span, Text},
-    widgets::{Padding, Paragraph, Wrap},
+    widgets::{List, ListItem, ListState, Padding, Paragraph, Wrap},
     Frame,
 };
 
@@ -13,6 +18,318 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
     render_normal_mode(f, app, area);
 }
 
+/// Mark a verse's text with its highlighter color: a background tint, or
+/// an underline in that color on the current verse so its own background
+/// still shows where the reader is
+fn highlighted(app: &App, verse: &Verse, style: Style, is_current: bool) -> Style {
+    match app.highlights.color_of(&verse.reference).map(|color| app.theme.highlighter(color)) {
+        Some(color) if is_current => style.underline_color(color).add_modifier(Modifier::UNDERLINED),
+        Some(color) => style.bg(color),
+        None => style,
+    }
+}
+
+/// Split a verse's text into spans styled by the translation's formatting
+/// and the reader's word marks, with the word cursor's selection shown
+/// reversed, footnote markers, and Strong's numbers when enabled
+fn verse_text_spans<'a>(
+    app: &App,
+    verse: &'a Verse,
+    base: Style,
+    cursor: Option<&WordCursor>,
+) -> Vec<Span<'a>> {
+    verse_line_spans(app, verse, base, cursor, &[]).concat()
+}
+
+/// Like `verse_text_spans`, but starting a new line at each of the char
+/// offsets in `breaks`
+fn verse_line_spans<'a>(
+    app: &App,
+    verse: &'a Verse,
+    base: Style,
+    cursor: Option<&WordCursor>,
+    breaks: &[usize],
+) -> Vec<Vec<Span<'a>>> {
+    let marks = app.word_marks.for_verse(&verse.reference, &app.translation);
+    let inserts = verse_inserts(app, verse);
+    if marks.is_empty()
+        && cursor.is_none()
+        && inserts.is_empty()
+        && verse.content.segments.is_empty()
+        && breaks.is_empty()
+    {
+        return vec![vec![Span::styled(verse.text.as_str(), base)]];
+    }
+
+    let selection = cursor.and_then(|cursor| {
+        let words = word_ranges(&verse.text);
+        let (first, last) = cursor.selected_words();
+        Some((words.get(first)?.0, words.get(last)?.1))
+    });
+
+    // Style of each char: the translation's formatting, then marks oldest first
+    let style_at = |idx: usize| {
+        let format = verse.content.style_at(idx);
+        let mut style = base;
+        if format.words_of_jesus {
+            style = style.fg(app.theme.red_letter);
+        }
+        if format.supplied {
+            style = style.add_modifier(Modifier::ITALIC);
+        }
+        if format.selah {
+            style = style.fg(app.theme.text_muted).add_modifier(Modifier::ITALIC);
+        }
+        for mark in marks.iter().filter(|m| m.start <= idx && idx < m.end) {
+            if let Some(color) = mark.color {
+                style = style.bg(app.theme.highlighter(color));
+            }
+            if mark.underline {
+                style = style.add_modifier(Modifier::UNDERLINED);
+            }
+            if mark.bold {
+                style = style.add_modifier(Modifier::BOLD);
+            }
+        }
+        if selection.is_some_and(|(start, end)| start <= idx && idx < end) {
+            style = style.add_modifier(Modifier::REVERSED);
+        }
+        (style, format.divine_name)
+    };
+
+    // Group runs of chars with the same style into spans, breaking runs
+    // where a marker or a new line goes
+    let mut lines = Vec::new();
+    let mut spans = Vec::new();
+    let mut inserts = inserts.into_iter().peekable();
+    let mut run_start = 0;
+    let mut run_style = style_at(0);
+    let push_run = |spans: &mut Vec<Span<'a>>, text: &'a str, (style, divine_name): (Style, bool)| {
+        if text.is_empty() {
+            return;
+        }
+        if divine_name {
+            spans.push(Span::styled(small_caps(text), style));
+        } else {
+            spans.push(Span::styled(text, style));
+        }
+    };
+    for (idx, (byte, _)) in verse.text.char_indices().enumerate() {
+        let style = style_at(idx);
+        let insert_here = inserts.peek().is_some_and(|(at, ..)| *at == idx);
+        let break_here = idx > 0 && breaks.contains(&idx);
+        if style != run_style || insert_here || break_here {
+            push_run(&mut spans, &verse.text[run_start..byte], run_style);
+            run_start = byte;
+            run_style = style;
+        }
+        // Markers belong to the word before them, so go before a line break
+        while let Some((_, marker, style)) = inserts.next_if(|(at, ..)| *at == idx) {
+            spans.push(Span::styled(marker, style));
+        }
+        if break_here {
+            lines.push(std::mem::take(&mut spans));
+        }
+    }
+    push_run(&mut spans, &verse.text[run_start..], run_style);
+    spans.extend(inserts.map(|(_, marker, style)| Span::styled(marker, style)));
+    lines.push(spans);
+
+    lines
+}
+
+/// Lines for the titles shown above a verse: section headings, psalm
+/// superscriptions and acrostic headings, aligned with the verse text
+fn title_lines<'a>(app: &App, verse: &'a Verse, indent: usize) -> Vec<Line<'a>> {
+    verse
+        .content
+        .titles
+        .iter()
+        .map(|title| {
+            let style = match title.kind {
+                TitleKind::Superscription => app.theme.text_muted().add_modifier(Modifier::ITALIC),
+                TitleKind::Acrostic => app.theme.heading(),
+                TitleKind::Section => app.theme.accent(),
+            };
+            Line::from(vec![Span::raw(" ".repeat(indent)), Span::styled(title.text.as_str(), style)])
+        })
+        .collect()
+}
+
+/// Lay a poetic verse out one poetry line per row, indented by level.
+/// `lead` (icons and verse number) goes before the first line, and later
+/// lines align with the text after it
+fn poetry_lines<'a>(
+    app: &App,
+    verse: &'a Verse,
+    base: Style,
+    cursor: Option<&WordCursor>,
+    lead: Vec<Span<'a>>,
+    width: usize,
+) -> Vec<Line<'a>> {
+    let gutter: usize = lead.iter().map(Span::width).sum();
+    let breaks: Vec<usize> = verse.content.lines.iter().map(|line| line.start).collect();
+    let first_level = verse
+        .content
+        .lines
+        .iter()
+        .find(|line| line.start == 0)
+        .map_or(1, |line| line.level);
+    let levels = std::iter::once(first_level)
+        .chain(verse.content.lines.iter().filter(|line| line.start > 0).map(|line| line.level));
+
+    let mut lead = Some(lead);
+    let mut lines = Vec::new();
+    for (part, level) in verse_line_spans(app, verse, base, cursor, &breaks).into_iter().zip(levels) {
+        let indent = 2 * usize::from(level.saturating_sub(1));
+        let mut spans = lead.take().unwrap_or_else(|| vec![Span::raw(" ".repeat(gutter))]);
+        spans.push(Span::raw(" ".repeat(indent)));
+        spans.extend(part);
+        lines.extend(wrap_spans(spans, width, gutter + indent + 4));
+    }
+    lines
+}
+
+/// Word-wrap spans to `width` columns, indenting continuation rows by
+/// `indent` so wrapped poetry keeps its shape. Words split across spans
+/// (a style change or marker mid-word) stay together
+fn wrap_spans<'a>(spans: Vec<Span<'a>>, width: usize, indent: usize) -> Vec<Line<'a>> {
+    // Break the spans into words, each keeping its trailing space
+    let mut words: Vec<Vec<Span<'a>>> = Vec::new();
+    let mut word_open = false;
+    for span in spans {
+        let style = span.style;
+        for piece in span.content.split_inclusive(' ') {
+            let piece = Span::styled(piece.to_string(), style);
+            match words.last_mut() {
+                Some(word) if word_open => word.push(piece),
+                _ => words.push(vec![piece]),
+            }
+            word_open = !piece_ends_word(words.last());
+        }
+    }
+
+    let mut lines = Vec::new();
+    let mut line: Vec<Span<'a>> = Vec::new();
+    let mut used = 0;
+    let mut line_start = 0;
+    for word in words {
+        let word_width: usize = word.iter().map(Span::width).sum();
+        let trailing = usize::from(piece_ends_word(Some(&word)));
+        if used > line_start && used + word_width - trailing > width {
+            lines.push(Line::from(std::mem::take(&mut line)));
+            line.push(Span::raw(" ".repeat(indent)));
+            used = indent;
+            line_start = indent;
+        }
+        used += word_width;
+        line.extend(word);
+    }
+    lines.push(Line::from(line));
+    lines
+}
+
+fn piece_ends_word(word: Option<&Vec<Span>>) -> bool {
+    word.and_then(|word| word.last()).is_some_and(|span| span.content.ends_with(' '))
+}
+
+/// Markers placed in a verse's text, keyed by the char offset they go
+/// before: footnote letters, and Strong's numbers after their words
+fn verse_inserts(app: &App, verse: &Verse) -> Vec<(usize, String, Style)> {
+    let mut inserts = Vec::new();
+
+    if app.settings.show_strongs {
+        let mut offset = 0;
+        for token in &verse.tokens {
+            offset += token.text.chars().count();
+            if !token.strongs.is_empty() {
+                let tag: String = token.strongs.iter().map(|number| superscript(number)).collect();
+                inserts.push((offset, tag, app.theme.text_muted()));
+            }
+        }
+    }
+
+    for (index, note) in verse.content.notes.iter().enumerate() {
+        inserts.push((note.offset, note_marker(index), app.theme.accent()));
+    }
+
+    // Stable, so a Strong's number stays next to its word before a footnote
+    inserts.sort_by_key(|(offset, ..)| *offset);
+    inserts
+}
+
+/// Superscript letter marking the verse's `index`th note: ᵃ, ᵇ, ᶜ...
+pub fn note_marker(index: usize) -> String {
+    const LETTERS: [char; 25] = [
+        'ᵃ', 'ᵇ', 'ᶜ', 'ᵈ', 'ᵉ', 'ᶠ', 'ᵍ', 'ʰ', 'ⁱ', 'ʲ', 'ᵏ', 'ˡ', 'ᵐ', 'ⁿ', 'ᵒ', 'ᵖ', 'ʳ', 'ˢ',
+        'ᵗ', 'ᵘ', 'ᵛ', 'ʷ', 'ˣ', 'ʸ', 'ᶻ',
+    ];
+    LETTERS.get(index).copied().unwrap_or('*').to_string()
+}
+
+/// Show the divine name in small caps: LORD -> Lᴏʀᴅ
+fn small_caps(text: &str) -> String {
+    let mut word_start = true;
+    text.chars()
+        .map(|c| {
+            let first = word_start;
+            word_start = !c.is_alphabetic();
+            if first {
+                return c;
+            }
+            match c.to_ascii_lowercase() {
+                'a' => 'ᴀ',
+                'b' => 'ʙ',
+                'c' => 'ᴄ',
+                'd' => 'ᴅ',
+                'e' => 'ᴇ',
+                'f' => 'ꜰ',
+                'g' => 'ɢ',
+                'h' => 'ʜ',
+                'i' => 'ɪ',
+                'j' => 'ᴊ',
+                'k' => 'ᴋ',
+                'l' => 'ʟ',
+                'm' => 'ᴍ',
+                'n' => 'ɴ',
+                'o' => 'ᴏ',
+                'p' => 'ᴘ',
+                'r' => 'ʀ',
+                's' => 'ꜱ',
+                't' => 'ᴛ',
+                'u' => 'ᴜ',
+                'v' => 'ᴠ',
+                'w' => 'ᴡ',
+                'y' => 'ʏ',
+                'z' => 'ᴢ',
+                _ => c,
+            }
+        })
+        .collect()
+}
+
+/// Render a Strong's or verse number in superscript characters: H430 -> ᴴ⁴³⁰
+fn superscript(number: &str) -> String {
+    number
+        .chars()
+        .map(|c| match c {
+            'H' => 'ᴴ',
+            'G' => 'ᴳ',
+            '0' => '⁰',
+            '1' => '¹',
+            '2' => '²',
+            '3' => '³',
+            '4' => '⁴',
+            '5' => '⁵',
+            '6' => '⁶',
+            '7' => '⁷',
+            '8' => '⁸',
+            '9' => '⁹',
+            other => other,
+        })
+        .collect()
+}
+
 /// Render normal reading mode with all UI elements
 fn render_normal_mode(f: &mut Frame, app: &App, area: Rect) {
     // Main layout: top bar + content + status bar
@@ -51,16 +368,328 @@ fn render_top_bar(f: &mut Frame, app: &App, area: Rect) {
     f.render_widget(title_widget, area);
 }
 
-/// Render the main reading area with elegant verse display
+/// Render the main reading area with elegant verse display, between the
+/// outline and the cross-reference and companion panels when they are open
 fn render_reading_area(f: &mut Frame, app: &App, area: Rect) {
-    render_verses(f, app, area);
+    let area = if app.outline_open {
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(25), Constraint::Percentage(75)])
+            .split(area);
+        render_outline(f, app, chunks[0]);
+        chunks[1]
+    } else {
+        area
+    };
+
+    // Side panels stack in the right column, cross-references on top
+    let panels: Vec<fn(&mut Frame, &App, Rect)> = [
+        (app.crossref_panel_open, render_cross_references as fn(&mut Frame, &App, Rect)),
+        (app.companion_open, render_companion),
+        (app.analytics_open, render_analytics),
+    ]
+    .into_iter()
+    .filter_map(|(open, render)| open.then_some(render))
+    .collect();
+
+    if panels.is_empty() {
+        render_verses(f, app, area);
+        return;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(62), Constraint::Percentage(38)])
+        .split(area);
+
+    render_verses(f, app, chunks[0]);
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Ratio(1, panels.len() as u32); panels.len()])
+        .split(chunks[1]);
+    for (render, row) in panels.into_iter().zip(rows.iter()) {
+        render(f, app, *row);
+    }
+}
+
+/// Render the analytics panel: counts, reading time, frequent content words
+/// and words distinctive of the chapter or book against the rest of the Bible
+fn render_analytics(f: &mut Frame, app: &App, area: Rect) {
+    let scope = match (&app.analytics_for, app.analytics_book) {
+        (Some((book, _)), true) => book.clone(),
+        (Some((book, Some(chapter))), false) => format!("{} {}", book, chapter),
+        _ => String::new(),
+    };
+    let title = format!("{} Analytics - {}", icons::CHART, scope);
+    let block = if app.analytics_focus {
+        app.theme.block_active_with_title(title)
+    } else {
+        app.theme.block_with_title(title)
+    };
+
+    let Some(stats) = &app.analytics else {
+        f.render_widget(
+            Paragraph::new("Nothing to count yet.")
+                .style(app.theme.text_muted())
+                .block(block),
+            area,
+        );
+        return;
+    };
+
+    let lines = analytics_lines(app, stats);
+    f.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .scroll((app.analytics_scroll, 0)),
+        area,
+    );
+}
+
+/// Lines of the analytics panel for some counts; `App::analytics_lines`
+/// counts them to bound scrolling
+fn analytics_lines(app: &App, stats: &WordCounts) -> Vec<Line<'static>> {
+    let tab = |label, active| {
+        if active {
+            Span::styled(format!(" {} ", label), app.theme.highlight_style())
+        } else {
+            Span::styled(format!(" {} ", label), app.theme.text_muted())
+        }
+    };
+    let figure = |label: &str, value: String| {
+        Line::from(vec![
+            Span::styled(format!("{:<16}", label), app.theme.text_secondary()),
+            Span::styled(value, app.theme.text_style()),
+        ])
+    };
+
+    let average = if stats.verses > 0 { stats.words as f64 / stats.verses as f64 } else { 0.0 };
+    let mut lines = vec![
+        Line::from(vec![tab("Chapter", !app.analytics_book), tab("Book", app.analytics_book)]),
+        Line::from(""),
+        figure("Verses", stats.verses.to_string()),
+        figure("Words", stats.words.to_string()),
+        figure("Words per verse", format!("{:.1}", average)),
+        figure("Reading time", format!("~{} min", stats.reading_minutes())),
+    ];
+
+    let mut word_list = |heading: &str, words: &[(String, usize)]| {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(heading.to_string(), app.theme.heading())));
+        if words.is_empty() {
+            lines.push(Line::from(Span::styled("  None", app.theme.text_muted())));
+        }
+        let max = words.first().map_or(1, |(_, count)| (*count).max(1));
+        for (word, count) in words {
+            let filled = (count * 10).div_ceil(max);
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {:<14}", word), app.theme.text_style()),
+                Span::styled(format!("{:>5} ", count), app.theme.text_muted()),
+                Span::styled("▮".repeat(filled), app.theme.accent()),
+            ]));
+        }
+    };
+
+    word_list("Most frequent", &app.analytics_frequent);
+    word_list("Distinctive (vs. rest of the Bible)", &app.analytics_distinctive);
+
+    lines
+}
+
+/// Render the companion pane: the selected module's entries for the
+/// current verse
+fn render_companion(f: &mut Frame, app: &App, area: Rect) {
+    let module = app.reference_module();
+    let title = match (module, &app.companion_verse) {
+        (Some(module), Some(reference)) => format!("{} {} - {}", icons::COMMENTARY, module.name, reference),
+        (Some(module), None) => format!("{} {}", icons::COMMENTARY, module.name),
+        (None, _) => format!("{} Commentary", icons::COMMENTARY),
+    };
+    let block = if app.companion_focus {
+        app.theme.block_active_with_title(title)
+    } else {
+        app.theme.block_with_title(title)
+    };
+
+    let message = match module {
+        None => Some("No reference modules imported.\nRun: biblios import-module <file>".to_string()),
+        Some(module) if app.companion_entries.is_empty() => {
+            Some(format!("No {} entry for this verse.", module.name))
+        }
+        Some(_) => None,
+    };
+    if let Some(message) = message {
+        f.render_widget(
+            Paragraph::new(message)
+                .style(app.theme.text_muted())
+                .wrap(Wrap { trim: true })
+                .block(block),
+            area,
+        );
+        return;
+    }
+
+    let mut lines = Vec::new();
+    for entry in &app.companion_entries {
+        if !lines.is_empty() {
+            lines.push(Line::from(""));
+        }
+        lines.push(Line::from(Span::styled(entry.key.as_str(), app.theme.heading())));
+        lines.extend(
+            entry
+                .text
+                .lines()
+                .map(|line| Line::from(Span::styled(line, app.theme.text_secondary()))),
+        );
+    }
+
+    f.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false })
+            .scroll((app.companion_scroll, 0)),
+        area,
+    );
+}
+
+/// Render the outline side panel: the current book's section headings, with
+/// the section being read marked
+fn render_outline(f: &mut Frame, app: &App, area: Rect) {
+    let title = match &app.state.current_book {
+        Some(book) => format!("{} Outline - {}", icons::OUTLINE, book),
+        None => format!("{} Outline", icons::OUTLINE),
+    };
+    let block = if app.outline_focus {
+        app.theme.block_active_with_title(title)
+    } else {
+        app.theme.block_with_title(title)
+    };
+
+    let outline = app.outline();
+    if outline.is_empty() {
+        f.render_widget(
+            Paragraph::new("No section headings in this book.")
+                .style(app.theme.text_muted())
+                .wrap(Wrap { trim: true })
+                .block(block),
+            area,
+        );
+        return;
+    }
+
+    let current = app.current_section();
+    let items: Vec<ListItem> = outline
+        .iter()
+        .enumerate()
+        .map(|(idx, heading)| {
+            let style = if Some(idx) == current {
+                app.theme.accent()
+            } else {
+                app.theme.text_secondary()
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{}:{} ", heading.reference.chapter, heading.reference.verse),
+                    app.theme.text_muted(),
+                ),
+                Span::styled(heading.text.as_str(), style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(app.theme.highlight_style())
+        .highlight_symbol("▶ ");
+
+    // Follow the section being read until the panel is focused
+    let mut state = ListState::default();
+    state.select(if app.outline_focus { Some(app.outline_selected) } else { current });
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+/// Render the cross-references side panel for the current verse
+fn render_cross_references(f: &mut Frame, app: &App, area: Rect) {
+    let title = match &app.crossref_verse {
+        Some(reference) => format!("{} Cross-references - {}", icons::ARROW_RIGHT, reference),
+        None => format!("{} Cross-references", icons::ARROW_RIGHT),
+    };
+    let block = if app.crossref_focus {
+        app.theme.block_active_with_title(title)
+    } else {
+        app.theme.block_with_title(title)
+    };
+
+    let message = if app.crossrefs.is_none() {
+        Some("No cross-references imported.\nRun: biblios import-crossrefs <file>")
+    } else if app.crossref_entries.is_empty() {
+        Some("No cross-references for this verse.")
+    } else {
+        None
+    };
+    if let Some(message) = message {
+        f.render_widget(
+            Paragraph::new(message)
+                .style(app.theme.text_muted())
+                .wrap(Wrap { trim: true })
+                .block(block),
+            area,
+        );
+        return;
+    }
+
+    // Votes scale the bar next to each reference
+    let max_votes = app
+        .crossref_entries
+        .iter()
+        .map(|(crossref, _)| crossref.votes)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+
+    let items: Vec<ListItem> = app
+        .crossref_entries
+        .iter()
+        .map(|(crossref, preview)| {
+            let filled = ((crossref.votes.max(0) * 5 + max_votes - 1) / max_votes) as usize;
+            ListItem::new(vec![
+                Line::from(vec![
+                    Span::styled(crossref.target.to_string(), app.theme.heading()),
+                    Span::styled(
+                        format!("  {}{}", "▮".repeat(filled), "▯".repeat(5 - filled.min(5))),
+                        app.theme.accent(),
+                    ),
+                ]),
+                Line::from(Span::styled(format!("  {}", preview), app.theme.text_muted())),
+            ])
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(app.theme.highlight_style())
+        .highlight_symbol("▶ ");
+
+    let mut state = ListState::default();
+    if app.crossref_focus {
+        state.select(Some(app.crossref_selected));
+    }
+    f.render_stateful_widget(list, area, &mut state);
 }
 
 /// Render verses with modern, elegant styling
 fn render_verses(f: &mut Frame, app: &App, area: Rect) {
-    let verses_text = if let Some(chapter) = &app.current_chapter {
+    let verses_text = if let (Some(chapter), ReadingLayout::Paragraphs) =
+        (&app.current_chapter, app.settings.layout)
+    {
+        paragraph_text(app, chapter, text_width(area))
+    } else if let Some(chapter) = &app.current_chapter {
         let height = area.height.saturating_sub(2) as usize;
         let current_idx = app.current_verse_index;
+        let width = text_width(area);
+        // Bookmark and note icons, then the verse number
+        let gutter = 5 + if app.settings.show_verse_numbers { 4 } else { 0 };
 
         // Use scroll offset for viewport - verses stay in place
         let start = app.scroll_offset;
@@ -71,6 +700,7 @@ fn render_verses(f: &mut Frame, app: &App, area: Rect) {
         for (idx, verse) in chapter.verses[start..end].iter().enumerate() {
             let actual_verse_idx = start + idx;
             let is_current = actual_verse_idx == current_idx;
+            lines.extend(title_lines(app, verse, gutter));
 
             // Create verse number
             let verse_num = if app.settings.show_verse_numbers {
@@ -97,32 +727,56 @@ fn render_verses(f: &mut Frame, app: &App, area: Rect) {
             // Create the line with proper styling
             let mut spans = vec![];
 
-            // Add bookmark indicator
+            // Add bookmark indicator; ranges mark every verse they cover, and
+            // a range being marked with 'M' shows from its anchor to the cursor
             let is_bookmarked = app.bookmarks.is_bookmarked(&verse.reference);
-            let bookmark_icon = if is_bookmarked {
+            let is_pending = match (&app.selection_anchor, chapter.verses.get(current_idx)) {
+                (Some(anchor), Some(cursor)) if anchor.book == verse.reference.book => {
+                    let anchor = (anchor.chapter, anchor.verse);
+                    let cursor = (cursor.reference.chapter, cursor.reference.verse);
+                    let position = (verse.reference.chapter, verse.reference.verse);
+                    anchor.min(cursor) <= position && position <= anchor.max(cursor)
+                }
+                _ => false,
+            };
+            let bookmark_icon = if is_bookmarked || is_pending {
                 format!("{} ", icons::BOOKMARK)
             } else {
                 "   ".to_string()
             };
-            spans.push(Span::styled(
-                bookmark_icon,
-                app.theme.bookmark(),
-            ));
+            let bookmark_style = if is_pending {
+                app.theme.text_muted()
+            } else {
+                app.theme.bookmark()
+            };
+            spans.push(Span::styled(bookmark_icon, bookmark_style));
+
+            // Add note indicator
+            let note_icon = if app.notes.has_note(&verse.reference) {
+                format!("{} ", icons::NOTE)
+            } else {
+                "  ".to_string()
+            };
+            spans.push(Span::styled(note_icon, app.theme.info()));
 
             // Add verse number
             if app.settings.show_verse_numbers {
                 spans.push(Span::styled(verse_num, num_style));
             }
 
-            // Add verse text
-            let text_span = if let Some(bg) = bg_color {
-                Span::styled(&verse.text, text_style.bg(bg))
-            } else {
-                Span::styled(&verse.text, text_style)
+            // Add verse text, marked with its highlighter color
+            let text_style = match bg_color {
+                Some(bg) => text_style.bg(bg),
+                None => text_style,
             };
-            spans.push(text_span);
-
-            lines.push(Line::from(spans));
+            let text_style = highlighted(app, verse, text_style, is_current);
+            let cursor = app.word_cursor.as_ref().filter(|_| is_current);
+            if verse.content.lines.is_empty() {
+                spans.extend(verse_text_spans(app, verse, text_style, cursor));
+                lines.push(Line::from(spans));
+            } else {
+                lines.extend(poetry_lines(app, verse, text_style, cursor, spans, width));
+            }
 
             // Add spacing between verses if enabled (always, not conditional on is_current)
             if app.settings.verse_spacing && idx < chapter.verses[start..end].len() - 1 {
@@ -133,7 +787,7 @@ fn render_verses(f: &mut Frame, app: &App, area: Rect) {
         Text::from(lines)
     } else {
         // Welcome screen with modern styling
-        let welcome_lines = vec![
+        let mut welcome_lines = vec![
             Line::from(""),
             Line::from(
                 Span::styled(
@@ -149,6 +803,27 @@ fn render_verses(f: &mut Frame, app: &App, area: Rect) {
                 )
             ).alignment(Alignment::Center),
             Line::from(""),
+        ];
+
+        // Verse of the day
+        if let Some(verse) = &app.daily_verse {
+            welcome_lines.extend([
+                Line::from(""),
+                Line::from(
+                    Span::styled("Verse of the Day", app.theme.heading())
+                ).alignment(Alignment::Center),
+                Line::from(""),
+                Line::from(
+                    Span::styled(verse.text.clone(), app.theme.text())
+                ).alignment(Alignment::Center),
+                Line::from(
+                    Span::styled(format!("— {}", verse.reference), app.theme.accent())
+                ).alignment(Alignment::Center),
+            ]);
+        }
+
+        welcome_lines.extend([
+            Line::from(""),
             Line::from(""),
             Line::from(
                 Span::styled(
@@ -183,7 +858,7 @@ fn render_verses(f: &mut Frame, app: &App, area: Rect) {
                 Span::styled("       ", Style::default()),
                 Span::styled("Show help", app.theme.text_secondary()),
             ]),
-        ];
+        ]);
 
         Text::from(welcome_lines)
     };
@@ -206,6 +881,69 @@ fn render_verses(f: &mut Frame, app: &App, area: Rect) {
     f.render_widget(paragraph, area);
 }
 
+/// Columns available for text inside the reader's border and padding
+fn text_width(area: Rect) -> usize {
+    area.width.saturating_sub(6) as usize
+}
+
+/// Lay a chapter out as prose: verses flow into paragraphs with superscript
+/// verse numbers, starting from the scroll offset. Poetry keeps its lines
+fn paragraph_text<'a>(app: &'a App, chapter: &'a Chapter, width: usize) -> Text<'a> {
+    let starts = paragraph_starts(&chapter.verses);
+    let mut lines = Vec::new();
+    let mut spans: Vec<Span> = Vec::new();
+
+    for (idx, verse) in chapter.verses.iter().enumerate().skip(app.scroll_offset) {
+        let poetry = !verse.content.lines.is_empty();
+        if starts[idx] && !spans.is_empty() {
+            lines.push(Line::from(std::mem::take(&mut spans)));
+            lines.push(Line::from(""));
+        } else if poetry && verse.content.paragraph && !lines.is_empty() {
+            lines.push(Line::from(""));
+        }
+        if !verse.content.titles.is_empty() || poetry {
+            if !spans.is_empty() {
+                lines.push(Line::from(std::mem::take(&mut spans)));
+            }
+            lines.extend(title_lines(app, verse, 0));
+        }
+        if !spans.is_empty() {
+            spans.push(Span::raw(" "));
+        }
+
+        let is_current = idx == app.current_verse_index;
+        let (num_style, text_style) = if is_current {
+            (app.theme.verse_number_current(), app.theme.current().bg(app.theme.bg_current))
+        } else {
+            (app.theme.verse_number(), app.theme.text_secondary())
+        };
+        let text_style = highlighted(app, verse, text_style, is_current);
+
+        if app.bookmarks.is_bookmarked(&verse.reference) {
+            spans.push(Span::styled(format!("{} ", icons::BOOKMARK), app.theme.bookmark()));
+        }
+        if app.notes.has_note(&verse.reference) {
+            spans.push(Span::styled(format!("{} ", icons::NOTE), app.theme.info()));
+        }
+        if app.settings.show_verse_numbers {
+            spans.push(Span::styled(superscript(&verse.reference.verse.to_string()), num_style));
+        }
+
+        let cursor = app.word_cursor.as_ref().filter(|_| is_current);
+        if poetry {
+            let lead = std::mem::take(&mut spans);
+            lines.extend(poetry_lines(app, verse, text_style, cursor, lead, width));
+        } else {
+            spans.extend(verse_text_spans(app, verse, text_style, cursor));
+        }
+    }
+    if !spans.is_empty() {
+        lines.push(Line::from(spans));
+    }
+
+    Text::from(lines)
+}
+
 /// Render elegant status bar with contextual information
 fn render_status_bar(f: &mut Frame, app: &App, area: Rect) {
     // Get current verse info
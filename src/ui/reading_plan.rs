@@ -0,0 +1,168 @@
+use crate::app::App;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    text::{Line, Span},
+    widgets::{Clear, Paragraph},
+    Frame,
+};
+
+/// Render the reading plan modal
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    // Create centered modal (60% width, 70% height)
+    let modal_area = centered_rect(60, 70, area);
+
+    // Clear the area behind the modal
+    f.render_widget(Clear, modal_area);
+
+    let (title, mut plan_text) = if app.plan_picking {
+        ("📅 Choose a Reading Plan", picker_lines(app))
+    } else {
+        ("📅 Today's Reading", today_lines(app))
+    };
+
+    plan_text.push(Line::from(""));
+    plan_text.push(Line::from(vec![
+        Span::styled("↑/↓", app.theme.accent()),
+        Span::styled(":Navigate  ", app.theme.text_muted()),
+        Span::styled("Enter", app.theme.accent()),
+        Span::styled(if app.plan_picking { ":Start  " } else { ":Read  " }, app.theme.text_muted()),
+        Span::styled("ESC", app.theme.accent()),
+        Span::styled(":Close", app.theme.text_muted()),
+    ]));
+
+    let block = app.theme.block_modal_with_title(title);
+
+    let paragraph = Paragraph::new(plan_text)
+        .block(block)
+        .style(app.theme.text());
+
+    f.render_widget(paragraph, modal_area);
+}
+
+/// Lines listing every plan with its description
+fn picker_lines(app: &App) -> Vec<Line<'_>> {
+    let mut lines = vec![Line::from("")];
+
+    for (idx, plan) in app.plans.iter().enumerate() {
+        let is_selected = app.plan_index == idx;
+        let prefix = if is_selected { "▶ " } else { "  " };
+        let is_active = app.plan_progress.plan_id.as_deref() == Some(plan.id.as_str());
+
+        lines.push(Line::from(vec![
+            Span::styled(prefix, app.theme.accent()),
+            Span::styled(
+                plan.name.as_str(),
+                if is_selected { app.theme.heading() } else { app.theme.text() },
+            ),
+            Span::styled(format!(" ({} days)", plan.len()), app.theme.text_muted()),
+            Span::styled(if is_active { " ✓ active" } else { "" }, app.theme.accent()),
+        ]));
+        if !plan.description.is_empty() {
+            lines.push(Line::from(Span::styled(
+                format!("    {}", plan.description),
+                app.theme.text_secondary(),
+            )));
+        }
+        lines.push(Line::from(""));
+    }
+
+    lines
+}
+
+/// Lines showing plan status and the readings still to do
+fn today_lines(app: &App) -> Vec<Line<'_>> {
+    let mut lines = vec![Line::from("")];
+
+    let Some(plan) = app.active_plan() else {
+        return lines;
+    };
+    let today = chrono::Local::now().date_naive();
+
+    lines.push(Line::from(Span::styled(plan.name.as_str(), app.theme.heading())));
+
+    if let Some(status) = app.plan_progress.status(plan, today) {
+        let behind = match status.days_behind {
+            0 => Span::styled("On track", app.theme.success()),
+            1 => Span::styled("1 day behind", app.theme.warning()),
+            days => Span::styled(format!("{} days behind", days), app.theme.warning()),
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("Day {} of {}  •  ", status.current_day, plan.len()),
+                app.theme.text_secondary(),
+            ),
+            behind,
+            Span::styled(
+                format!("  •  {}/{} readings done", status.completed, status.total),
+                app.theme.text_secondary(),
+            ),
+        ]));
+    }
+    lines.push(Line::from(""));
+
+    let outstanding = app.outstanding_readings();
+    let current_day = app.plan_progress.day_for(plan, today).unwrap_or(1);
+
+    if outstanding.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  All caught up for today!",
+            app.theme.text_muted(),
+        )));
+    }
+
+    for (idx, (day, index)) in outstanding.iter().enumerate() {
+        let is_selected = app.plan_index == idx;
+        let prefix = if is_selected { "▶ " } else { "  " };
+        let label = if *day == current_day {
+            "Today".to_string()
+        } else {
+            format!("Day {}", day)
+        };
+        let passage = plan.days[day - 1][*index].to_string();
+
+        lines.push(Line::from(vec![
+            Span::styled(prefix, app.theme.accent()),
+            Span::styled(
+                format!("{:<8}", label),
+                if *day == current_day { app.theme.accent() } else { app.theme.warning() },
+            ),
+            Span::styled(
+                passage,
+                if is_selected { app.theme.heading() } else { app.theme.text() },
+            ),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    let is_selected = app.plan_index == outstanding.len();
+    lines.push(Line::from(vec![
+        Span::styled(if is_selected { "▶ " } else { "  " }, app.theme.accent()),
+        Span::styled(
+            "Change plan…",
+            if is_selected { app.theme.heading() } else { app.theme.text_secondary() },
+        ),
+    ]));
+
+    lines
+}
+
+/// Helper function to create a centered rect
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(popup_layout[1])[1]
+}
@@ -1,8 +1,8 @@
 use crate::app::App;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    text::Line,
-    widgets::{Block, Borders, List, ListItem, Paragraph},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
     Frame,
 };
 
@@ -28,7 +28,7 @@ fn render_search_input(f: &mut Frame, app: &App, area: Rect) {
             Block::default()
                 .borders(Borders::ALL)
                 .border_style(app.theme.border_style())
-                .title(" Search (ESC to close) "),
+                .title(" Search (Enter to search, ESC to close) "),
         );
 
     f.render_widget(input, area);
@@ -42,33 +42,64 @@ fn render_search_input(f: &mut Frame, app: &App, area: Rect) {
     }
 }
 
-/// Render search results
+/// Render search results, under a header per rendering for Strong's searches
 fn render_search_results(f: &mut Frame, app: &App, area: Rect) {
-    let results: Vec<ListItem> = if app.search_results.is_empty() {
-        vec![ListItem::new(Line::from("No results found. Type to search..."))]
+    let mut items = Vec::new();
+    let mut selected_item = None;
+
+    if app.search_results.is_empty() {
+        items.push(ListItem::new(Line::from(
+            "No results. Type a query and press Enter (strong:G26 or lemma:agape for Strong's)",
+        )));
     } else {
-        app.search_results
-            .iter()
-            .enumerate()
-            .map(|(i, reference)| {
-                let style = if i == app.search_selected {
-                    app.theme.highlight_style()
-                } else {
-                    app.theme.text_style()
-                };
-                ListItem::new(reference.to_string()).style(style)
-            })
-            .collect()
-    };
+        // Group sizes add up to the result count; plain text searches have one unnamed group
+        let groups: Vec<(Option<&str>, usize)> = if app.search_groups.is_empty() {
+            vec![(None, app.search_results.len())]
+        } else {
+            app.search_groups
+                .iter()
+                .map(|(rendering, count)| (Some(rendering.as_str()), *count))
+                .collect()
+        };
+
+        let mut results = app.search_results.iter().enumerate();
+        for (rendering, count) in groups {
+            if let Some(rendering) = rendering {
+                let verses = if count == 1 { "verse" } else { "verses" };
+                items.push(ListItem::new(Line::from(vec![
+                    Span::styled(format!("\"{}\"", rendering), app.theme.heading()),
+                    Span::styled(format!("  {} {}", count, verses), app.theme.text_muted()),
+                ])));
+            }
 
-    let list = List::new(results)
+            for (i, reference) in results.by_ref().take(count) {
+                if i == app.search_selected {
+                    selected_item = Some(items.len());
+                }
+                let preview = app
+                    .verse_previews
+                    .get(reference)
+                    .map(String::as_str)
+                    .unwrap_or("");
+                items.push(ListItem::new(Line::from(vec![
+                    Span::styled(format!("{:<12}", reference.to_string()), app.theme.accent()),
+                    Span::styled(preview.to_string(), app.theme.text_style()),
+                ])));
+            }
+        }
+    }
+
+    let list = List::new(items)
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .border_style(app.theme.border_style())
                 .title(format!(" Results ({}) ", app.search_results.len())),
         )
-        .style(app.theme.text_style());
+        .style(app.theme.text_style())
+        .highlight_style(app.theme.highlight_style());
 
-    f.render_widget(list, area);
+    let mut state = ListState::default();
+    state.select(selected_item);
+    f.render_stateful_widget(list, area, &mut state);
 }
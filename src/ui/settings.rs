@@ -1,4 +1,6 @@
 use crate::app::App;
+use crate::bible::daily::DailyVerseSource;
+use crate::config::settings::ReadingLayout;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     text::{Line, Span},
@@ -51,6 +53,48 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
         Span::styled(spacing_value, if is_selected_2 { app.theme.accent() } else { app.theme.text() }),
         Span::styled(" Enter", app.theme.text_muted()),
     ]));
+    settings_text.push(Line::from(""));
+
+    // Verse of the day source (index 3)
+    let is_selected_3 = app.settings_selected_index == 3;
+    let daily_prefix = if is_selected_3 { "▶ " } else { "  " };
+    let daily_value = match app.settings.daily_verse {
+        DailyVerseSource::Curated => "Curated",
+        DailyVerseSource::Random => "Random",
+    };
+    settings_text.push(Line::from(vec![
+        Span::styled(daily_prefix, app.theme.accent()),
+        Span::styled("Verse of the Day: ", if is_selected_3 { app.theme.heading() } else { app.theme.text_secondary() }),
+        Span::styled(daily_value, if is_selected_3 { app.theme.accent() } else { app.theme.text() }),
+        Span::styled(" Enter", app.theme.text_muted()),
+    ]));
+    settings_text.push(Line::from(""));
+
+    // Strong's numbers (index 4)
+    let is_selected_4 = app.settings_selected_index == 4;
+    let strongs_prefix = if is_selected_4 { "▶ " } else { "  " };
+    let strongs_value = if app.settings.show_strongs { "Yes" } else { "No" };
+    settings_text.push(Line::from(vec![
+        Span::styled(strongs_prefix, app.theme.accent()),
+        Span::styled("Show Strong's Numbers: ", if is_selected_4 { app.theme.heading() } else { app.theme.text_secondary() }),
+        Span::styled(strongs_value, if is_selected_4 { app.theme.accent() } else { app.theme.text() }),
+        Span::styled(" Enter", app.theme.text_muted()),
+    ]));
+    settings_text.push(Line::from(""));
+
+    // Reading layout (index 5)
+    let is_selected_5 = app.settings_selected_index == 5;
+    let layout_prefix = if is_selected_5 { "▶ " } else { "  " };
+    let layout_value = match app.settings.layout {
+        ReadingLayout::Verses => "Verses",
+        ReadingLayout::Paragraphs => "Paragraphs",
+    };
+    settings_text.push(Line::from(vec![
+        Span::styled(layout_prefix, app.theme.accent()),
+        Span::styled("Layout: ", if is_selected_5 { app.theme.heading() } else { app.theme.text_secondary() }),
+        Span::styled(layout_value, if is_selected_5 { app.theme.accent() } else { app.theme.text() }),
+        Span::styled(" Enter", app.theme.text_muted()),
+    ]));
 
     settings_text.push(Line::from(""));
     settings_text.push(Line::from(""));
@@ -0,0 +1,247 @@
+use crate::app::App;
+use crate::ui::icons::{PROGRESS_0, PROGRESS_100, PROGRESS_50};
+use crate::ui::themes::Theme;
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Bar, BarChart, BarGroup, Block, Borders, Gauge, Paragraph},
+    Frame,
+};
+
+/// Weeks shown in the calendar heatmap
+const HEATMAP_WEEKS: i64 = 26;
+
+/// Days shown in the recent reading time chart
+const RECENT_DAYS: i64 = 14;
+
+/// Render the reading statistics dashboard
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(app.theme.border_style())
+        .title(" Reading Statistics - ESC to close ");
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Whole-Bible coverage gauge
+            Constraint::Length(2), // Streaks and reading time
+            Constraint::Min(0),    // Per-book coverage and calendar
+        ])
+        .split(inner);
+
+    let today = Local::now().date_naive();
+    let stats = app.history.stats(today);
+
+    let ratio = if stats.chapters_total == 0 {
+        0.0
+    } else {
+        stats.chapters_read as f64 / stats.chapters_total as f64
+    };
+    let gauge = Gauge::default()
+        .gauge_style(Style::default().fg(app.theme.accent_success).bg(app.theme.bg_selection))
+        .ratio(ratio)
+        .label(format!(
+            "Whole Bible: {}/{} chapters ({:.1}%)",
+            stats.chapters_read,
+            stats.chapters_total,
+            ratio * 100.0
+        ));
+    f.render_widget(gauge, chunks[0]);
+
+    let summary = Line::from(vec![
+        Span::styled(" Current streak: ", app.theme.text_secondary()),
+        Span::styled(format_days(stats.current_streak), app.theme.accent()),
+        Span::styled("   Longest streak: ", app.theme.text_secondary()),
+        Span::styled(format_days(stats.longest_streak), app.theme.accent()),
+        Span::styled("   Reading days: ", app.theme.text_secondary()),
+        Span::styled(stats.reading_days.to_string(), app.theme.accent()),
+        Span::styled("   Average per day: ", app.theme.text_secondary()),
+        Span::styled(format_duration(stats.average_seconds), app.theme.accent()),
+    ]);
+    f.render_widget(Paragraph::new(vec![Line::from(""), summary]), chunks[1]);
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(34), Constraint::Min(0)])
+        .split(chunks[2]);
+
+    render_book_coverage(f, app, columns[0]);
+
+    let right = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(11), Constraint::Min(0)])
+        .split(columns[1]);
+
+    render_heatmap(f, app, right[0], today);
+    render_recent_days(f, app, right[1], today);
+}
+
+/// Per-book coverage list with progress icons
+fn render_book_coverage(f: &mut Frame, app: &App, area: Rect) {
+    let lines: Vec<Line> = app
+        .history
+        .book_coverage()
+        .into_iter()
+        .skip(app.stats_scroll)
+        .map(|(book, read, total)| {
+            let (icon, style) = if read == 0 {
+                (PROGRESS_0, app.theme.text_muted())
+            } else if read == total {
+                (PROGRESS_100, app.theme.success())
+            } else {
+                (PROGRESS_50, app.theme.accent())
+            };
+
+            Line::from(vec![
+                Span::styled(format!(" {} ", icon), style),
+                Span::styled(format!("{:<7}", book), app.theme.text()),
+                Span::styled(progress_bar(read, total, 12), style),
+                Span::styled(format!(" {:>3}/{:<3}", read, total), app.theme.text_muted()),
+            ])
+        })
+        .collect();
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(app.theme.border_style())
+            .title(" Books (j/k) "),
+    );
+    f.render_widget(paragraph, area);
+}
+
+/// Calendar heatmap of reading time: one column per week, one row per weekday
+fn render_heatmap(f: &mut Frame, app: &App, area: Rect, today: NaiveDate) {
+    let daily = app.history.daily_seconds();
+
+    // Start on the Monday HEATMAP_WEEKS - 1 weeks before this week
+    let this_monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+    let first = this_monday - Duration::weeks(HEATMAP_WEEKS - 1);
+
+    let max = daily
+        .range(first..=today)
+        .map(|(_, seconds)| *seconds)
+        .max()
+        .unwrap_or(0);
+
+    let labels = ["Mon", "", "Wed", "", "Fri", "", "Sun"];
+    let mut lines = Vec::new();
+
+    for (weekday, label) in labels.iter().enumerate() {
+        let mut spans = vec![Span::styled(format!(" {:<4}", label), app.theme.text_muted())];
+
+        for week in 0..HEATMAP_WEEKS {
+            let date = first + Duration::days(week * 7 + weekday as i64);
+            if date > today {
+                spans.push(Span::raw("  "));
+                continue;
+            }
+            let seconds = daily.get(&date).copied().unwrap_or(0);
+            let level = heat_level(seconds, max);
+            spans.push(Span::styled("■ ", Style::default().fg(heat_color(&app.theme, level))));
+        }
+
+        lines.push(Line::from(spans));
+    }
+
+    lines.push(Line::from(""));
+    let mut legend = vec![Span::styled("      Less ", app.theme.text_muted())];
+    for level in 0..=4 {
+        legend.push(Span::styled("■ ", Style::default().fg(heat_color(&app.theme, level))));
+    }
+    legend.push(Span::styled("More", app.theme.text_muted()));
+    lines.push(Line::from(legend));
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(app.theme.border_style())
+            .title(format!(" Last {} weeks ", HEATMAP_WEEKS)),
+    );
+    f.render_widget(paragraph, area);
+}
+
+/// Bar chart of minutes read over the last few days
+fn render_recent_days(f: &mut Frame, app: &App, area: Rect, today: NaiveDate) {
+    let daily = app.history.daily_seconds();
+
+    let bars: Vec<Bar> = (0..RECENT_DAYS)
+        .rev()
+        .map(|offset| {
+            let date = today - Duration::days(offset);
+            let minutes = daily.get(&date).copied().unwrap_or(0) / 60;
+            Bar::default()
+                .value(minutes)
+                .label(Line::from(date.format("%d").to_string()))
+                .text_value(if minutes > 0 { minutes.to_string() } else { String::new() })
+        })
+        .collect();
+
+    let chart = BarChart::default()
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(app.theme.border_style())
+                .title(" Minutes per day "),
+        )
+        .data(BarGroup::default().bars(&bars))
+        .bar_width(3)
+        .bar_gap(1)
+        .bar_style(Style::default().fg(app.theme.accent_primary))
+        .value_style(Style::default().fg(app.theme.text_inverse).bg(app.theme.accent_primary))
+        .label_style(app.theme.text_muted());
+    f.render_widget(chart, area);
+}
+
+/// Bucket a day's reading time into 0 (none) to 4 (most)
+fn heat_level(seconds: u64, max: u64) -> u8 {
+    if seconds == 0 || max == 0 {
+        0
+    } else {
+        (1 + seconds * 3 / max).min(4) as u8
+    }
+}
+
+/// Color for a heat level, blending from the selection background to the success accent
+fn heat_color(theme: &Theme, level: u8) -> Color {
+    match (theme.bg_selection, theme.accent_success) {
+        (Color::Rgb(r0, g0, b0), Color::Rgb(r1, g1, b1)) => {
+            let blend = |from: u8, to: u8| {
+                (from as i32 + (to as i32 - from as i32) * level as i32 / 4) as u8
+            };
+            Color::Rgb(blend(r0, r1), blend(g0, g1), blend(b0, b1))
+        }
+        (empty, _) if level == 0 => empty,
+        (_, full) => full,
+    }
+}
+
+/// Text progress bar of the given width
+fn progress_bar(done: usize, total: usize, width: usize) -> String {
+    let filled = (done * width).checked_div(total).unwrap_or(0);
+    format!("{}{}", "█".repeat(filled), "░".repeat(width - filled))
+}
+
+/// Format a streak length
+fn format_days(days: usize) -> String {
+    if days == 1 {
+        "1 day".to_string()
+    } else {
+        format!("{} days", days)
+    }
+}
+
+/// Format seconds as "1h 05m" or "12m"
+fn format_duration(seconds: u64) -> String {
+    let minutes = seconds / 60;
+    if minutes >= 60 {
+        format!("{}h {:02}m", minutes / 60, minutes % 60)
+    } else {
+        format!("{}m", minutes)
+    }
+}
@@ -1,3 +1,4 @@
+use crate::config::HighlightColor;
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::widgets::{Block, Borders, BorderType};
 
@@ -38,6 +39,12 @@ pub struct Theme {
     // Special colors
     pub bookmark_color: Color,     // Bookmark indicator
     pub search_highlight: Color,   // Search result highlight
+
+    /// Highlighter background tints, in `HighlightColor::ALL` order
+    pub highlight_palette: [Color; 5],
+
+    /// Words of Jesus in red-letter translations
+    pub red_letter: Color,
 }
 
 impl Theme {
@@ -74,6 +81,17 @@ impl Theme {
 
             bookmark_color: Color::Rgb(255, 200, 100),
             search_highlight: Color::Rgb(255, 255, 100),
+
+            // Yellow, green, blue, pink, orange
+            highlight_palette: [
+                Color::Rgb(80, 72, 20),
+                Color::Rgb(30, 70, 40),
+                Color::Rgb(30, 50, 90),
+                Color::Rgb(85, 35, 65),
+                Color::Rgb(90, 50, 20),
+            ],
+
+            red_letter: Color::Rgb(230, 95, 95),
         }
     }
 
@@ -161,6 +179,11 @@ impl Theme {
         Style::default().fg(self.bookmark_color)
     }
 
+    /// Background tint for a highlighter color
+    pub fn highlighter(&self, color: HighlightColor) -> Color {
+        self.highlight_palette[color.index()]
+    }
+
     /// Verse number style
     pub fn verse_number(&self) -> Style {
         Style::default().fg(self.text_muted)
@@ -346,6 +369,16 @@ pub fn gruvbox_dark() -> Theme {
     theme.bookmark_color = Color::Rgb(254, 128, 25);    // orange
     theme.search_highlight = Color::Rgb(250, 189, 47);
 
+    theme.highlight_palette = [
+        Color::Rgb(87, 75, 30),
+        Color::Rgb(55, 70, 40),
+        Color::Rgb(40, 60, 70),
+        Color::Rgb(85, 45, 60),
+        Color::Rgb(95, 55, 25),
+    ];
+
+    theme.red_letter = Color::Rgb(251, 73, 52);
+
     theme
 }
 
@@ -382,6 +415,16 @@ pub fn nord_theme() -> Theme {
     theme.bookmark_color = Color::Rgb(208, 135, 112); // nord12
     theme.search_highlight = Color::Rgb(235, 203, 139);
 
+    theme.highlight_palette = [
+        Color::Rgb(95, 90, 70),
+        Color::Rgb(75, 95, 75),
+        Color::Rgb(65, 85, 110),
+        Color::Rgb(100, 75, 95),
+        Color::Rgb(105, 80, 70),
+    ];
+
+    theme.red_letter = Color::Rgb(191, 97, 106);
+
     theme
 }
 
@@ -418,6 +461,16 @@ pub fn solarized_dark() -> Theme {
     theme.bookmark_color = Color::Rgb(203, 75, 22);    // orange
     theme.search_highlight = Color::Rgb(181, 137, 0);
 
+    theme.highlight_palette = [
+        Color::Rgb(60, 65, 20),
+        Color::Rgb(25, 75, 40),
+        Color::Rgb(10, 70, 100),
+        Color::Rgb(80, 40, 70),
+        Color::Rgb(85, 50, 20),
+    ];
+
+    theme.red_letter = Color::Rgb(220, 50, 47);
+
     theme
 }
 
@@ -453,6 +506,16 @@ pub fn light_theme() -> Theme {
     theme.bookmark_color = Color::Rgb(220, 120, 20);
     theme.search_highlight = Color::Rgb(255, 240, 100);
 
+    theme.highlight_palette = [
+        Color::Rgb(255, 245, 160),
+        Color::Rgb(200, 240, 200),
+        Color::Rgb(200, 225, 255),
+        Color::Rgb(255, 210, 230),
+        Color::Rgb(255, 220, 180),
+    ];
+
+    theme.red_letter = Color::Rgb(175, 30, 30);
+
     theme
 }
 
@@ -488,6 +551,16 @@ pub fn dracula_theme() -> Theme {
     theme.bookmark_color = Color::Rgb(255, 184, 108); // orange
     theme.search_highlight = Color::Rgb(241, 250, 140);
 
+    theme.highlight_palette = [
+        Color::Rgb(90, 90, 55),
+        Color::Rgb(45, 85, 60),
+        Color::Rgb(55, 65, 110),
+        Color::Rgb(100, 50, 90),
+        Color::Rgb(105, 70, 50),
+    ];
+
+    theme.red_letter = Color::Rgb(255, 85, 85);
+
     theme
 }
 
@@ -523,6 +596,16 @@ pub fn monokai_theme() -> Theme {
     theme.bookmark_color = Color::Rgb(253, 151, 31);    // orange
     theme.search_highlight = Color::Rgb(230, 219, 116);
 
+    theme.highlight_palette = [
+        Color::Rgb(85, 80, 40),
+        Color::Rgb(60, 80, 25),
+        Color::Rgb(35, 70, 85),
+        Color::Rgb(90, 30, 60),
+        Color::Rgb(95, 60, 20),
+    ];
+
+    theme.red_letter = Color::Rgb(249, 38, 114);
+
     theme
 }
 
@@ -26,8 +26,8 @@ fn render_book_selector(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
     // Filter books based on search
     let filtered_books = filter_books(&app.selector_search);
 
-    // Convert to SelectItem
-    let items: Vec<SelectItem> = filtered_books
+    // Convert to SelectItem, with matching section headings after the books
+    let mut items: Vec<SelectItem> = filtered_books
         .iter()
         .map(|(short, full, testament, _)| {
             let icon = icons::category_icon(short);
@@ -36,6 +36,11 @@ fn render_book_selector(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
                 .with_description(*testament)
         })
         .collect();
+    items.extend(app.filtered_headings().into_iter().map(|heading| {
+        SelectItem::new(heading.text.as_str(), heading.reference.to_string())
+            .with_icon(icons::OUTLINE)
+            .with_description(heading.reference.to_string())
+    }));
 
     let config = FilteredSelectConfig {
         title: format!("{} Go To Book", icons::SEARCH),
@@ -43,7 +48,7 @@ fn render_book_selector(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
         search_query: &app.selector_search,
         selected_index: app.selector_index,
         show_search: true,
-        search_placeholder: "Type to search books...",
+        search_placeholder: "Type to search books or section headings...",
         help_text: None,
     };
 
@@ -62,6 +67,7 @@ fn render_chapter_selector(f: &mut Frame, app: &App, area: ratatui::layout::Rect
         &title,
         chapter_count as usize,
         app.selector_index,
+        app.read_chapters.chapters(book_name),
         &app.theme,
         area,
     );
@@ -72,12 +78,8 @@ fn render_verse_selector(f: &mut Frame, app: &App, area: ratatui::layout::Rect)
     let book_name = app.selector_selected_book.as_deref().unwrap_or("Unknown");
     let chapter_num = app.selector_selected_chapter.unwrap_or(1);
 
-    // Get verse count from loaded chapter
-    let verse_count = if let Some(chapter) = &app.current_chapter {
-        chapter.verses.len()
-    } else {
-        50 // Default estimate
-    };
+    // Get verse count from versification data
+    let verse_count = bible::versification::verse_count(book_name, chapter_num).unwrap_or(0) as usize;
 
     let title = format!("{} {} {} - Select Verse", icons::BOOK, book_name, chapter_num);
 
@@ -86,6 +88,7 @@ fn render_verse_selector(f: &mut Frame, app: &App, area: ratatui::layout::Rect)
         &title,
         verse_count,
         app.selector_index,
+        &[],
         &app.theme,
         area,
     );
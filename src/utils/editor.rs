@@ -0,0 +1,90 @@
+use anyhow::{bail, Context, Result};
+use std::env;
+use std::fs;
+use std::process::Command;
+
+/// Separator between the reference header and the note body in the temp file
+const BODY_SEPARATOR: &str = "---";
+
+/// The user's preferred editor: `$VISUAL`, then `$EDITOR`, then `vi`
+pub fn preferred_editor() -> String {
+    ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(|var| env::var(var).ok())
+        .find(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| "vi".to_string())
+}
+
+/// Open `contents` in the external editor as a temporary Markdown file and
+/// return the edited contents
+///
+/// The terminal must already be out of raw mode and the alternate screen.
+pub fn edit_in_editor(file_stem: &str, contents: &str) -> Result<String> {
+    let path = env::temp_dir().join(format!("biblios-{}-{}.md", file_stem, std::process::id()));
+    fs::write(&path, contents).context("Failed to write temporary note file")?;
+
+    // The editor setting may carry arguments, e.g. "code --wait"
+    let editor = preferred_editor();
+    let mut parts = editor.split_whitespace();
+    let program = parts.next().unwrap_or("vi");
+
+    let status = Command::new(program)
+        .args(parts)
+        .arg(&path)
+        .status()
+        .with_context(|| format!("Failed to launch editor '{}'", editor));
+
+    let edited = fs::read_to_string(&path).context("Failed to read temporary note file");
+    let _ = fs::remove_file(&path);
+
+    if !status?.success() {
+        bail!("Editor '{}' exited with an error; note not changed", editor);
+    }
+    edited
+}
+
+/// Build the Markdown document for a note: a reference header, the verse
+/// text as a quote, then the note body below a separator
+pub fn note_document(heading: &str, verse_text: &str, note: &str) -> String {
+    format!(
+        "# {}\n\n> {}\n\n{}\n\n{}\n",
+        heading, verse_text, BODY_SEPARATOR, note
+    )
+}
+
+/// Extract the note body from an edited document
+///
+/// Everything after the first separator line is the note. If the user deleted
+/// the separator, a leading `#` header and `>` quote are dropped instead.
+pub fn note_from_document(document: &str) -> String {
+    let lines: Vec<&str> = document.lines().collect();
+
+    let body_start = match lines.iter().position(|line| line.trim() == BODY_SEPARATOR) {
+        Some(pos) => pos + 1,
+        None => lines
+            .iter()
+            .position(|line| {
+                let line = line.trim_start();
+                !(line.is_empty() || line.starts_with('#') || line.starts_with('>'))
+            })
+            .unwrap_or(lines.len()),
+    };
+
+    lines[body_start..].join("\n").trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_note_document_round_trip() {
+        let doc = note_document("John 3:16 (KJV)", "For God so loved the world", "Line one\n\nLine two");
+        assert!(doc.starts_with("# John 3:16 (KJV)\n"));
+        assert_eq!(note_from_document(&doc), "Line one\n\nLine two");
+
+        // Separator removed: header and quote are still stripped
+        assert_eq!(note_from_document("# John 3:16\n> text\n\nMy note\n"), "My note");
+        assert_eq!(note_from_document(&note_document("John 3:16", "text", "")), "");
+    }
+}
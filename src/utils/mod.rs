@@ -1 +1,2 @@
 // Utility modules
+pub mod editor;